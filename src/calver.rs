@@ -0,0 +1,360 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use crate::{ParseSemverError, ReleaseKind, guess_kind};
+
+const MAX_SEGMENTS: usize = 8;
+
+/// A part of a [`Layout`], named as on calver.org
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Token {
+    /// Full year (e.g. 2006, 2016)
+    Yyyy,
+    /// Short year, counted from 2000 (e.g. 6, 16, 106)
+    Yy,
+    /// Zero-padded short year (e.g. 06, 16, 106)
+    ZeroY,
+    /// Month (e.g. 1, 11)
+    Mm,
+    /// Zero-padded month (e.g. 01, 11)
+    ZeroM,
+    /// Week of the year (e.g. 1, 33)
+    Ww,
+    /// Zero-padded week of the year (e.g. 01, 33)
+    ZeroW,
+    /// Day of the month (e.g. 1, 31)
+    Dd,
+    /// Zero-padded day of the month (e.g. 01, 31)
+    ZeroD,
+    Major,
+    /// Optional, like the tokens after it
+    Minor,
+    Micro,
+    /// An optional text tag (e.g. rc1, dev)
+    Modifier,
+}
+
+impl Token {
+    const NAMES: [(&str, Self); 13] = [
+        ("YYYY", Self::Yyyy), ("YY", Self::Yy), ("0Y", Self::ZeroY),
+        ("MM", Self::Mm), ("0M", Self::ZeroM), ("WW", Self::Ww), ("0W", Self::ZeroW),
+        ("DD", Self::Dd), ("0D", Self::ZeroD),
+        ("MAJOR", Self::Major), ("MINOR", Self::Minor), ("MICRO", Self::Micro), ("MODIFIER", Self::Modifier),
+    ];
+
+    const fn is_optional(self) -> bool {
+        matches!(self, Self::Minor | Self::Micro | Self::Modifier)
+    }
+
+    /// The minimum and maximum number of digits
+    const fn width(self) -> (usize, usize) {
+        match self {
+            Self::Yyyy => (4, 4),
+            Self::Yy => (1, 3),
+            Self::ZeroY => (2, 3),
+            Self::Mm | Self::Ww | Self::Dd => (1, 2),
+            Self::ZeroM | Self::ZeroW | Self::ZeroD => (2, 2),
+            Self::Major | Self::Minor | Self::Micro | Self::Modifier => (1, usize::MAX),
+        }
+    }
+}
+
+/// The layout of a calendar version, such as `YYYY.0M.0D` or `YY.MM.MICRO`
+///
+/// Wherever the layout has a separator, any of `.`, `-` or `_` is accepted.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Layout {
+    segments: [(Option<char>, Token); MAX_SEGMENTS],
+    len: usize,
+}
+
+impl Layout {
+    fn segments(&self) -> &[(Option<char>, Token)] {
+        &self.segments[..self.len]
+    }
+}
+
+impl Default for Layout {
+    /// `YYYY.MM.DD`
+    fn default() -> Self {
+        let mut segments = [(None, Token::Yyyy); MAX_SEGMENTS];
+        segments[1] = (Some('.'), Token::Mm);
+        segments[2] = (Some('.'), Token::Dd);
+        Self { segments, len: 3 }
+    }
+}
+
+/// Why a [`Layout`] couldn't be parsed
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseLayoutError {
+    /// Text that isn't a token or a single separator between tokens
+    UnrecognizedText(String),
+    /// More tokens than a layout can hold
+    TooManyTokens,
+    /// A layout without any tokens
+    Empty,
+    /// A separator with no token after it
+    TrailingSeparator,
+}
+
+impl fmt::Display for ParseLayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnrecognizedText(text) => write!(f, "Unrecognized text at {text}"),
+            Self::TooManyTokens => write!(f, "Layouts have at most {MAX_SEGMENTS} tokens"),
+            Self::Empty => write!(f, "Empty layout"),
+            Self::TrailingSeparator => write!(f, "Layout ends with a separator"),
+        }
+    }
+}
+
+impl std::error::Error for ParseLayoutError {}
+
+impl FromStr for Layout {
+    type Err = ParseLayoutError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = [(None, Token::Yyyy); MAX_SEGMENTS];
+        let mut len = 0;
+        let mut sep = None;
+        let mut rest = s;
+
+        while let Some(c) = rest.chars().next() {
+            if let Some((name, token)) = Token::NAMES.iter().find(|(name, _)| rest.starts_with(name)) {
+                if len == MAX_SEGMENTS {
+                    return Err(ParseLayoutError::TooManyTokens)
+                }
+
+                segments[len] = (sep.take(), *token);
+                len += 1;
+                rest = &rest[name.len()..];
+            } else if matches!(c, '.' | '-' | '_') && sep.is_none() && len > 0 {
+                sep = Some(c);
+                rest = &rest[1..];
+            } else {
+                return Err(ParseLayoutError::UnrecognizedText(rest.to_owned()))
+            }
+        }
+
+        if len == 0 {
+            return Err(ParseLayoutError::Empty)
+        }
+        if sep.is_some() {
+            return Err(ParseLayoutError::TrailingSeparator)
+        }
+
+        Ok(Self { segments, len })
+    }
+}
+
+impl fmt::Display for Layout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (sep, token) in self.segments() {
+            if let Some(sep) = sep {
+                write!(f, "{sep}")?;
+            }
+
+            let (name, _) = Token::NAMES.iter().find(|(_, t)| t == token).expect("Every token is named");
+            write!(f, "{name}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A calendar version, parsed according to some [`Layout`]
+///
+/// Versions sort chronologically, whatever order the layout puts the date in, then by any
+/// `MAJOR`, `MINOR` and `MICRO`. A modifier makes a pre-release of the version without it.
+#[derive(Debug, Default, Clone)]
+pub struct Calver {
+    pub year: Option<u32>,
+    pub month: Option<u32>,
+    pub week: Option<u32>,
+    pub day: Option<u32>,
+    pub major: Option<u64>,
+    pub minor: Option<u64>,
+    pub micro: Option<u64>,
+    pub modifier: Option<String>,
+    /// The layout the version was parsed with
+    pub layout: Layout,
+}
+
+const fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Splits off the digits of `token` from the start of `s`
+fn digits(s: &str, token: Token) -> Option<(&str, &str)> {
+    let (min, max) = token.width();
+    let len = s.bytes().take(max).take_while(u8::is_ascii_digit).count();
+    if len < min { None } else { Some(s.split_at(len)) }
+}
+
+impl Calver {
+    pub fn parse_with(s: &str, layout: Layout) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+
+        if !s.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(ParseSemverError::MissingMajor)
+        }
+
+        let mut calver = Self { layout, ..Default::default() };
+        let mut rest = s;
+
+        for &(sep, token) in layout.segments() {
+            let after = match sep {
+                Some(_) => rest.strip_prefix(['.', '-', '_']),
+                None => Some(rest),
+            };
+
+            let matched = after.and_then(|after| {
+                if token == Token::Modifier {
+                    let len = after.bytes().take_while(u8::is_ascii_alphanumeric).count();
+                    return (len > 0).then(|| after.split_at(len))
+                }
+
+                digits(after, token)
+            });
+
+            // optional parts may simply be left out
+            let Some((value, remaining)) = matched else {
+                if token.is_optional() {
+                    continue
+                }
+                return Err(ParseSemverError::UnrecognizedText)
+            };
+            rest = remaining;
+
+            let num = || value.parse::<u64>().map_err(|_| ParseSemverError::Overflow(value.to_owned()));
+            let date = || value.parse::<u32>().map_err(|_| ParseSemverError::Overflow(value.to_owned()));
+
+            match token {
+                Token::Yyyy => calver.year = Some(date()?),
+                Token::Yy | Token::ZeroY => calver.year = Some(2000 + date()?),
+                Token::Mm | Token::ZeroM => calver.month = Some(date()?),
+                Token::Ww | Token::ZeroW => calver.week = Some(date()?),
+                Token::Dd | Token::ZeroD => calver.day = Some(date()?),
+                Token::Major => calver.major = Some(num()?),
+                Token::Minor => calver.minor = Some(num()?),
+                Token::Micro => calver.micro = Some(num()?),
+                Token::Modifier => calver.modifier = Some(value.to_owned()),
+            }
+        }
+
+        if !rest.is_empty() {
+            return Err(ParseSemverError::UnrecognizedText)
+        }
+
+        let max_day = match (calver.year, calver.month) {
+            (Some(year), Some(month)) => days_in_month(year, month),
+            _ => 31,
+        };
+
+        if calver.month.is_some_and(|m| !(1..=12).contains(&m))
+            || calver.week.is_some_and(|w| !(1..=53).contains(&w))
+            || calver.day.is_some_and(|d| !(1..=max_day).contains(&d))
+        {
+            return Err(ParseSemverError::InvalidDate)
+        }
+
+        Ok(calver)
+    }
+
+    /// The closest [`ReleaseKind`], for filtering, guessed from the modifier
+    pub fn rkind(&self) -> ReleaseKind {
+        self.modifier.as_deref().map_or(ReleaseKind::Stable, guess_kind)
+    }
+
+    fn cmp_modifier(&self, other: &Self) -> Ordering {
+        let count = |m: &str| {
+            let digits = m.trim_start_matches(|c: char| !c.is_ascii_digit());
+            digits.parse::<u64>().unwrap_or(0)
+        };
+
+        match (&self.modifier, &other.modifier) {
+            (Some(a), Some(b)) => {
+                self.rkind().cmp(&other.rkind())
+                    .then_with(|| count(a).cmp(&count(b)))
+                    .then_with(|| a.cmp(b))
+            },
+            (a, b) => a.is_none().cmp(&b.is_none()),
+        }
+    }
+
+    /// Displays the version in `layout`, rather than the one it was parsed with
+    ///
+    /// Date parts the version doesn't have are shown as zero, and optional ones are left out.
+    pub fn display_as(&self, layout: Layout) -> impl fmt::Display + '_ {
+        fmt::from_fn(move |f| {
+            for &(sep, token) in layout.segments() {
+                let number = match token {
+                    Token::Yyyy => Some(u64::from(self.year.unwrap_or(0))),
+                    Token::Yy | Token::ZeroY => Some(u64::from(self.year.unwrap_or(2000).saturating_sub(2000))),
+                    Token::Mm | Token::ZeroM => Some(u64::from(self.month.unwrap_or(0))),
+                    Token::Ww | Token::ZeroW => Some(u64::from(self.week.unwrap_or(0))),
+                    Token::Dd | Token::ZeroD => Some(u64::from(self.day.unwrap_or(0))),
+                    Token::Major => Some(self.major.unwrap_or(0)),
+                    Token::Minor => self.minor,
+                    Token::Micro => self.micro,
+                    Token::Modifier => None,
+                };
+
+                let text = match (token, number) {
+                    (Token::Modifier, _) => self.modifier.clone(),
+                    (_, Some(n)) => Some(format!("{n:0width$}", width = token.width().0)),
+                    (_, None) => None,
+                };
+
+                let Some(text) = text else { continue };
+
+                if let Some(sep) = sep {
+                    write!(f, "{sep}")?;
+                }
+                write!(f, "{text}")?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl PartialEq for Calver {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Calver {}
+
+impl PartialOrd for Calver {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Calver {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.year.cmp(&other.year)
+            .then_with(|| self.month.cmp(&other.month))
+            .then_with(|| self.week.cmp(&other.week))
+            .then_with(|| self.day.cmp(&other.day))
+            .then_with(|| self.major.cmp(&other.major))
+            .then_with(|| self.minor.unwrap_or(0).cmp(&other.minor.unwrap_or(0)))
+            .then_with(|| self.micro.unwrap_or(0).cmp(&other.micro.unwrap_or(0)))
+            .then_with(|| self.cmp_modifier(other))
+    }
+}
+
+impl fmt::Display for Calver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_as(self.layout))
+    }
+}
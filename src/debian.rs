@@ -0,0 +1,151 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+
+use crate::{ParseSemverError, ReleaseKind, guess_kind};
+
+/// A Debian package version, `[epoch:]upstream[-revision]`
+///
+/// Ordering follows `dpkg --compare-versions`, so `~` sorts before everything, even the end of the
+/// string (e.g. `1.0~rc1 < 1.0`).
+#[derive(Debug, Default, Clone)]
+pub struct Debian {
+    pub epoch: u64,
+    pub upstream: String,
+    pub revision: Option<String>,
+}
+
+impl Debian {
+    pub fn parse(s: &str) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+
+        let (epoch, rest) = match s.split_once(':') {
+            Some((epoch, rest)) => {
+                let epoch = epoch.parse::<u64>().map_err(|_| ParseSemverError::UnrecognizedText)?;
+                (epoch, rest)
+            },
+            None => (0, s),
+        };
+
+        let (upstream, revision) = match rest.rsplit_once('-') {
+            Some((upstream, revision)) => (upstream, Some(revision)),
+            None => (rest, None),
+        };
+
+        if !upstream.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(ParseSemverError::MissingMajor)
+        }
+
+        let valid = |part: &str, extra: &[char]| {
+            part.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '~') || extra.contains(&c))
+        };
+
+        // upstream may only contain hyphens if there's a revision, and colons if there's an epoch
+        let upstream_extra = match (revision.is_some(), s.contains(':')) {
+            (true, true) => &['-', ':'][..],
+            (true, false) => &['-'][..],
+            (false, true) => &[':'][..],
+            (false, false) => &[][..],
+        };
+
+        if !valid(upstream, upstream_extra) || revision.is_some_and(|r| r.is_empty() || !valid(r, &[])) {
+            return Err(ParseSemverError::UnrecognizedText)
+        }
+
+        Ok(Self { epoch, upstream: upstream.to_owned(), revision: revision.map(str::to_owned) })
+    }
+
+    /// The closest [`ReleaseKind`], for filtering
+    ///
+    /// Anything after a tilde in the upstream version is taken to be a pre-release.
+    pub fn rkind(&self) -> ReleaseKind {
+        match self.upstream.split_once('~') {
+            Some((_, pre)) => guess_kind(pre),
+            None => ReleaseKind::Stable,
+        }
+    }
+}
+
+/// The weight of a character in the lexical pass, where `0` marks a digit or the end of the string
+fn order(c: Option<u8>) -> i32 {
+    match c {
+        None => 0,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => c as i32,
+        Some(b'~') => -1,
+        Some(c) => c as i32 + 256,
+    }
+}
+
+/// Compares two upstream versions or revisions like dpkg's `verrevcmp()`
+fn verrevcmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.as_bytes(), b.as_bytes());
+
+    while !a.is_empty() || !b.is_empty() {
+        // lexical pass, up to the next digit of either string
+        while a.first().is_some_and(|c| !c.is_ascii_digit()) || b.first().is_some_and(|c| !c.is_ascii_digit()) {
+            let (ac, bc) = (order(a.first().copied()), order(b.first().copied()));
+            if ac != bc {
+                return ac.cmp(&bc)
+            }
+            a = &a[1..];
+            b = &b[1..];
+        }
+
+        // numeric pass, ignoring leading zeros
+        let a_digits = a.iter().take_while(|c| c.is_ascii_digit()).count();
+        let b_digits = b.iter().take_while(|c| c.is_ascii_digit()).count();
+        let a_num = std::str::from_utf8(&a[..a_digits]).unwrap_or_default().trim_start_matches('0');
+        let b_num = std::str::from_utf8(&b[..b_digits]).unwrap_or_default().trim_start_matches('0');
+
+        let ord = a_num.len().cmp(&b_num.len()).then_with(|| a_num.cmp(b_num));
+        if ord.is_ne() {
+            return ord
+        }
+
+        a = &a[a_digits..];
+        b = &b[b_digits..];
+    }
+
+    Ordering::Equal
+}
+
+impl PartialEq for Debian {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Debian {}
+
+impl PartialOrd for Debian {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Debian {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch.cmp(&other.epoch)
+            .then_with(|| verrevcmp(&self.upstream, &other.upstream))
+            .then_with(|| {
+                verrevcmp(self.revision.as_deref().unwrap_or_default(), other.revision.as_deref().unwrap_or_default())
+            })
+    }
+}
+
+impl fmt::Display for Debian {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.epoch != 0 {
+            write!(f, "{}:", self.epoch)?;
+        }
+
+        write!(f, "{}", self.upstream)?;
+
+        if let Some(revision) = &self.revision {
+            write!(f, "-{revision}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use core::fmt;
+
+use std::str::FromStr;
+
+/// A range of fields to sort lines by, like `sort -k`
+///
+/// Fields are counted from 1, and are separated by runs of whitespace unless a separator is given.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Key {
+    pub start: usize,
+    /// The last field, inclusive, or the end of the line if `None`
+    pub end: Option<usize>,
+    /// Compare the fields as plain text, rather than as a version
+    pub lexical: bool,
+}
+
+impl Key {
+    /// The text of the key's fields in `line`, which is empty if the line is too short
+    pub fn extract<'a>(&self, line: &'a str, separator: Option<char>) -> &'a str {
+        let spans = match separator {
+            Some(sep) => {
+                let mut start = 0;
+                line.split(sep)
+                    .map(|field| {
+                        let span = (start, start + field.len());
+                        start = span.1 + sep.len_utf8();
+                        span
+                    })
+                    .collect::<Vec<_>>()
+            },
+            None => line.split_whitespace()
+                .map(|field| {
+                    let start = field.as_ptr() as usize - line.as_ptr() as usize;
+                    (start, start + field.len())
+                })
+                .collect(),
+        };
+
+        let end = self.end.unwrap_or(spans.len()).min(spans.len());
+        if self.start == 0 || self.start > end {
+            return ""
+        }
+
+        &line[spans[self.start - 1].0..spans[end - 1].1]
+    }
+}
+
+/// Why a [`Key`] couldn't be parsed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseKeyError {
+    /// A field that isn't a number from 1 up
+    InvalidField,
+    /// An end field before the start field
+    EndBeforeStart,
+}
+
+impl fmt::Display for ParseKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidField => write!(f, "Fields are numbered from 1"),
+            Self::EndBeforeStart => write!(f, "The end field comes before the start"),
+        }
+    }
+}
+
+impl std::error::Error for ParseKeyError {}
+
+impl FromStr for Key {
+    type Err = ParseKeyError;
+
+    /// Parses `N[,M]`, optionally followed by `l` for a lexical key
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (s, lexical) = match s.strip_suffix('l') {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+
+        let field = |n: &str| match n.parse::<usize>() {
+            Ok(0) | Err(_) => Err(ParseKeyError::InvalidField),
+            Ok(n) => Ok(n),
+        };
+
+        let (start, end) = match s.split_once(',') {
+            Some((start, end)) => (field(start)?, Some(field(end)?)),
+            None => (field(s)?, None),
+        };
+
+        if end.is_some_and(|end| end < start) {
+            return Err(ParseKeyError::EndBeforeStart)
+        }
+
+        Ok(Self { start, end, lexical })
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.start)?;
+
+        if let Some(end) = self.end {
+            write!(f, ",{end}")?;
+        }
+
+        if self.lexical {
+            write!(f, "l")?;
+        }
+
+        Ok(())
+    }
+}
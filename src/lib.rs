@@ -0,0 +1,956 @@
+use core::fmt;
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+mod calver;
+mod debian;
+mod key;
+mod npm;
+mod pep440;
+mod perl;
+mod portage;
+mod range;
+mod req;
+mod rpm;
+mod semver2;
+mod version;
+
+pub use calver::{Calver, Layout, ParseLayoutError};
+pub use debian::Debian;
+pub use key::{Key, ParseKeyError};
+pub use npm::{Bound, NpmComparator, NpmRange};
+pub use pep440::{LocalPart, Pep440, PreLabel};
+pub use perl::{Perl, PerlForm};
+pub use portage::Portage;
+pub use range::{Constraint, Op, Range};
+pub use req::{Comparator, ReqOp, VersionReq};
+pub use rpm::Rpm;
+pub use semver2::{Identifier, Semver2};
+pub use version::{Scheme, Version};
+
+static RECOGNIZED_RE:   LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"[0-9][-_\.]?(dev|pre|next|alpha|[^a-z]a|beta|[^a-z]b|r?c|patch|[^a-z]p)"#).expect("Invalid regex"));
+static COUNT_IS_CHAR:   LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"[^a-z]([a-z])$"#).expect("Invalid regex"));
+
+static RKIND_DEV:       LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"dev"#).expect("Invalid regex"));
+static RKIND_PRE:       LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"pre"#).expect("Invalid regex"));
+static RKIND_NEXT:      LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"next"#).expect("Invalid regex"));
+static RKIND_ALPHA:     LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(alpha|a)([0-9]+)?$"#).expect("Invalid regex"));
+static RKIND_BETA:      LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(beta|b)([0-9]+)?$"#).expect("Invalid regex"));
+static RKIND_RC:        LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^r?c([0-9]+)?$"#).expect("Invalid regex"));
+static RKIND_PATCH:     LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(patch|p)([0-9]+)?$"#).expect("Invalid regex"));
+
+macro_rules! vprint     { ($opts:expr, $($arg:tt)*) => {{ if $opts.verbose { eprint!($($arg)*); } }}; }
+macro_rules! vprintln   { ($opts:expr, $($arg:tt)*) => {{ if $opts.verbose { eprintln!($($arg)*); } }}; }
+
+/// Settings that influence how versions are parsed and displayed
+///
+/// ```
+/// let opts = versort::ParseOptions::new().lenient(true).charcount(true);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct ParseOptions {
+    scheme: Scheme,
+    lenient: bool,
+    charcount: bool,
+    verbose: bool,
+    missing: Missing,
+    padding: Padding,
+    arbitrary_precision: bool,
+    perl_form: Option<PerlForm>,
+    calver_form: Option<Layout>,
+}
+
+impl ParseOptions {
+    pub const fn new() -> Self {
+        Self { scheme: Scheme::Semver, lenient: false, charcount: false, verbose: false, missing: Missing::Less, padding: Padding::Ignore, arbitrary_precision: false, perl_form: None, calver_form: None }
+    }
+
+    /// Parse and order versions under `scheme`
+    pub const fn scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Accept versions containing text that isn't a recognized release kind
+    pub const fn lenient(mut self, yes: bool) -> Self {
+        self.lenient = yes;
+        self
+    }
+
+    /// Treat a single trailing character as a counter (e.g. 3.5a)
+    pub const fn charcount(mut self, yes: bool) -> Self {
+        self.charcount = yes;
+        self
+    }
+
+    /// Print parsing details to stderr
+    pub const fn verbose(mut self, yes: bool) -> Self {
+        self.verbose = yes;
+        self
+    }
+
+    /// Order versions with fewer numeric components according to `missing`
+    pub const fn missing(mut self, missing: Missing) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    /// Order numeric components with leading zeros according to `padding`
+    pub const fn padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Accept numeric components too large for a `u64`, instead of failing with
+    /// [`ParseSemverError::Overflow`]
+    pub const fn arbitrary_precision(mut self, yes: bool) -> Self {
+        self.arbitrary_precision = yes;
+        self
+    }
+
+    /// Display [`Perl`] versions in `form`, instead of the form they were written in
+    pub const fn perl_form(mut self, form: Option<PerlForm>) -> Self {
+        self.perl_form = form;
+        self
+    }
+
+    /// Display [`Calver`] versions in `layout`, instead of the one they were parsed with
+    pub const fn calver_form(mut self, layout: Option<Layout>) -> Self {
+        self.calver_form = layout;
+        self
+    }
+}
+
+/// How a missing numeric component compares against one that's present
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum Missing {
+    /// Fewer components sort first (e.g. 1.0 < 1.0.0)
+    #[default]
+    Less,
+    /// Fewer components sort last (e.g. 1.0.0 < 1.0)
+    Greater,
+    /// Missing components count as zero (e.g. 1 == 1.0 == 1.0.0)
+    Zero,
+}
+
+/// How leading zeros in numeric components affect ordering
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum Padding {
+    /// Leading zeros don't matter (e.g. 001 == 1)
+    #[default]
+    Ignore,
+    /// Components with more leading zeros sort first (e.g. 001 < 01 < 1)
+    PaddedFirst,
+    /// Components past the first are decimal fractions (e.g. 1.10 < 1.9, 1.05 < 1.5)
+    Fraction,
+}
+
+/// Which original string survives when de-duplicating equal versions
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Keep {
+    #[default]
+    First,
+    Last,
+    Shortest,
+    /// Keeps the first, to be displayed in its formatted form
+    Canonical,
+}
+
+/// Which part of the sorted output to keep
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Select {
+    /// The first `n` versions, like `head -n`
+    First(usize),
+    /// The last `n` versions, like `tail -n`
+    Last(usize),
+}
+
+/// A condition that versions must satisfy to be kept
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Filter {
+    Range(Range),
+    Req(VersionReq),
+    Npm(NpmRange),
+}
+
+impl Filter {
+    /// Whether `version` satisfies the filter
+    ///
+    /// Requirements and npm ranges only ever match [`Version::Semver`].
+    pub fn matches(&self, version: &Version) -> bool {
+        match self {
+            Self::Range(range) => range.matches(version),
+            Self::Req(req) => version.as_semver().is_some_and(|s| req.matches(s)),
+            Self::Npm(range) => version.as_semver().is_some_and(|s| range.matches(s)),
+        }
+    }
+}
+
+/// A regex picking the version out of a line, such as `refs/tags/v(.*)`
+///
+/// The version is the group named `v`, or else the first capture group that matched, or else the
+/// whole match.
+#[derive(Debug, Clone)]
+pub struct Extract(Regex);
+
+impl Extract {
+    pub fn new(re: &str) -> Result<Self, regex::Error> {
+        Regex::new(re).map(Self)
+    }
+
+    /// The version text in `s`, if the regex matches
+    pub fn find<'a>(&self, s: &'a str) -> Option<&'a str> {
+        let caps = self.0.captures(s)?;
+        // groups in an alternative that didn't match are skipped
+        let m = caps.name("v").or_else(|| caps.iter().skip(1).flatten().next()).or_else(|| caps.get(0))?;
+        Some(m.as_str())
+    }
+}
+
+impl PartialEq for Extract {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for Extract {}
+
+/// Settings for sorting a list of versions
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct SortOptions {
+    parse: ParseOptions,
+    ignore: bool,
+    reverse: bool,
+    unique: Option<Keep>,
+    select: Option<Select>,
+    kinds: Kinds,
+    filters: Vec<Filter>,
+    separator: Option<char>,
+    keys: Vec<Key>,
+    extract: Option<Extract>,
+}
+
+impl SortOptions {
+    pub const fn new() -> Self {
+        Self { parse: ParseOptions::new(), ignore: false, reverse: false, unique: None, select: None, kinds: Kinds::all(), filters: Vec::new(), separator: None, keys: Vec::new(), extract: None }
+    }
+
+    /// Parse each version with `opts`
+    pub const fn parse(mut self, opts: ParseOptions) -> Self {
+        self.parse = opts;
+        self
+    }
+
+    /// Skip versions that could not be parsed instead of failing
+    pub const fn ignore(mut self, yes: bool) -> Self {
+        self.ignore = yes;
+        self
+    }
+
+    /// Sort newest first, keeping equal versions in input order
+    pub const fn reverse(mut self, yes: bool) -> Self {
+        self.reverse = yes;
+        self
+    }
+
+    /// Collapse equal versions into one, chosen by `keep`
+    pub const fn unique(mut self, keep: Option<Keep>) -> Self {
+        self.unique = keep;
+        self
+    }
+
+    /// Keep only part of the output, without sorting everything
+    pub const fn select(mut self, select: Option<Select>) -> Self {
+        self.select = select;
+        self
+    }
+
+    /// Drop versions whose release kind isn't in `kinds`
+    pub const fn kinds(mut self, kinds: Kinds) -> Self {
+        self.kinds = kinds;
+        self
+    }
+
+    /// Drop versions that don't satisfy `filter`, in addition to any previous filters
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Split lines into fields at `separator`, instead of at runs of whitespace
+    pub const fn separator(mut self, separator: Option<char>) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Sort by `key`, after any previous keys
+    ///
+    /// The version is parsed from the first key that isn't lexical, or the whole line if there is
+    /// none, and the remaining keys break ties between equal versions.
+    pub fn key(mut self, key: Key) -> Self {
+        self.keys.push(key);
+        self
+    }
+
+    /// Parse versions from the part of each line (or key) matched by `extract`
+    pub fn extract(mut self, extract: Option<Extract>) -> Self {
+        self.extract = extract;
+        self
+    }
+
+    pub const fn parse_options(&self) -> &ParseOptions {
+        &self.parse
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum ReleaseKind {
+    Dev,
+    Pre,
+    Next,
+    Alpha,
+    Beta,
+    Rc,
+    #[default]
+    Stable,
+    Patch,
+}
+
+impl ReleaseKind {
+    pub const ALL: [Self; 8] = [
+        Self::Dev, Self::Pre, Self::Next, Self::Alpha, Self::Beta, Self::Rc, Self::Stable, Self::Patch,
+    ];
+}
+
+impl FromStr for ReleaseKind {
+    type Err = ParseSemverError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "dev" => Ok(Self::Dev),
+            "pre" => Ok(Self::Pre),
+            "next" => Ok(Self::Next),
+            "alpha" => Ok(Self::Alpha),
+            "beta" => Ok(Self::Beta),
+            "rc" => Ok(Self::Rc),
+            "stable" => Ok(Self::Stable),
+            "patch" => Ok(Self::Patch),
+            _ => Err(ParseSemverError::UnrecognizedText),
+        }
+    }
+}
+
+/// A set of [`ReleaseKind`]s
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Kinds(u8);
+
+impl Kinds {
+    pub const fn all() -> Self {
+        Self(u8::MAX)
+    }
+
+    pub const fn none() -> Self {
+        Self(0)
+    }
+
+    /// Stable releases and patches
+    pub const fn stable() -> Self {
+        Self::none().with(ReleaseKind::Stable).with(ReleaseKind::Patch)
+    }
+
+    /// `kind` and every kind that sorts after it
+    pub const fn at_least(kind: ReleaseKind) -> Self {
+        Self(u8::MAX << kind as u8)
+    }
+
+    pub const fn with(self, kind: ReleaseKind) -> Self {
+        Self(self.0 | 1 << kind as u8)
+    }
+
+    pub const fn intersect(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub const fn contains(self, kind: ReleaseKind) -> bool {
+        self.0 & 1 << kind as u8 != 0
+    }
+}
+
+impl Default for Kinds {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl FromIterator<ReleaseKind> for Kinds {
+    fn from_iter<I: IntoIterator<Item = ReleaseKind>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::none(), Self::with)
+    }
+}
+
+/// Guesses the [`ReleaseKind`] of some pre-release text (e.g. `rc1` or `beta.2`)
+pub(crate) fn guess_kind(pre: &str) -> ReleaseKind {
+    let pre = pre.to_ascii_lowercase();
+    match pre.trim_start_matches(|c: char| !c.is_ascii_alphabetic()) {
+        s if s.starts_with("dev") => ReleaseKind::Dev,
+        s if s.starts_with("next") => ReleaseKind::Next,
+        s if s.starts_with("alpha") || s.starts_with('a') => ReleaseKind::Alpha,
+        s if s.starts_with("beta") || s.starts_with('b') => ReleaseKind::Beta,
+        s if s.starts_with("rc") => ReleaseKind::Rc,
+        _ => ReleaseKind::Pre,
+    }
+}
+
+/// The kind and count of the pre-release `tail` of `version` in an npm range or Cargo requirement
+///
+/// A numeric-only pre-release is below any named one, so `-0` is the lowest possible pre-release
+/// and the likes of `-1` are just above it. Named pre-releases are read as a [`Semver`] reads them.
+pub(crate) fn parse_pre(version: &str, tail: &str, opts: &ParseOptions) -> Result<(ReleaseKind, Option<u64>), ParseSemverError> {
+    let ids = tail.strip_prefix('-').map(|pre| pre.split('.').collect::<Vec<_>>()).unwrap_or_default();
+
+    if !ids.is_empty() && ids.iter().all(|id| !id.is_empty() && id.bytes().all(|c| c.is_ascii_digit())) {
+        let n = ids[0].parse::<u64>().map_err(|_| ParseSemverError::Overflow(ids[0].to_owned()))?;
+        return Ok(if n == 0 && ids.len() == 1 { (ReleaseKind::Dev, None) } else { (ReleaseKind::Dev, Some(n)) })
+    }
+
+    let semver = Semver::parse_with(version, opts)?;
+    Ok((semver.rkind, semver.count))
+}
+
+/// A numeric component of a [`Semver`], kept as the digits it was written with
+///
+/// Components compare numerically, however many digits they have.
+#[derive(Debug, Default, Clone)]
+pub struct Component(String);
+
+impl Component {
+    /// The digits as written, including any leading zeros
+    pub fn digits(&self) -> &str {
+        &self.0
+    }
+
+    /// The numeric value, if it fits in a `u64`
+    pub fn value(&self) -> Option<u64> {
+        self.0.parse().ok()
+    }
+
+    fn significant(&self) -> &str {
+        self.0.trim_start_matches('0')
+    }
+}
+
+impl PartialEq for Component {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Component {}
+
+impl PartialOrd for Component {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Component {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let (a, b) = (self.significant(), other.significant());
+        a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+    }
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.significant() {
+            "" => write!(f, "0"),
+            digits => write!(f, "{digits}"),
+        }
+    }
+}
+
+/// A loosely semantic version, with any number of numeric components
+///
+/// Components are compared in order, and when one version runs out of them first, `missing`
+/// decides which sorts first. Both `missing` and `padding` are taken from the [`ParseOptions`]
+/// the version was parsed with, and versions parsed with different rules are ordered by the rules
+/// first, so that every pair is compared the same way.
+///
+/// ```
+/// use versort::{Missing, Padding, ParseOptions, Semver};
+///
+/// let less = Semver::parse_with("1.0", &ParseOptions::new()).unwrap();
+/// let zero = Semver::parse_with("1.0.0", &ParseOptions::new().missing(Missing::Zero)).unwrap();
+/// assert!(less < zero && zero > less);
+/// assert_ne!(less, zero);
+///
+/// let padded = Semver::parse_with("1.01", &ParseOptions::new().padding(Padding::PaddedFirst)).unwrap();
+/// let fraction = Semver::parse_with("1.1", &ParseOptions::new().padding(Padding::Fraction)).unwrap();
+/// assert_eq!(padded.cmp(&fraction), fraction.cmp(&padded).reverse());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Semver {
+    pub components: Vec<Component>,
+    missing: Missing,
+    padding: Padding,
+    pub rkind: ReleaseKind,
+    pub count: Option<u64>,
+}
+
+impl Semver {
+    /// The `i`th numeric component, saturating at `u64::MAX`
+    fn component(&self, i: usize) -> Option<u64> {
+        self.components.get(i).map(|c| c.value().unwrap_or(u64::MAX))
+    }
+
+    pub fn major(&self) -> u64 {
+        self.component(0).unwrap_or_default()
+    }
+
+    pub fn minor(&self) -> Option<u64> {
+        self.component(1)
+    }
+
+    pub fn patch(&self) -> Option<u64> {
+        self.component(2)
+    }
+
+    /// Compares two components under this version's `padding`, which both sides share
+    fn cmp_component(&self, i: usize, a: &Component, b: &Component) -> std::cmp::Ordering {
+        match self.padding {
+            Padding::Ignore => a.cmp(b),
+            Padding::PaddedFirst => a.cmp(b).then_with(|| b.0.len().cmp(&a.0.len())),
+            Padding::Fraction if i > 0 => a.0.trim_end_matches('0').cmp(b.0.trim_end_matches('0')),
+            Padding::Fraction => a.cmp(b),
+        }
+    }
+
+    fn cmp_components(&self, other: &Self) -> std::cmp::Ordering {
+        // the rules must match before either side's can be applied
+        let rules = (self.missing, self.padding).cmp(&(other.missing, other.padding));
+        if rules.is_ne() {
+            return rules
+        }
+
+        for i in 0..self.components.len().max(other.components.len()) {
+            let ord = match (self.components.get(i), other.components.get(i), self.missing) {
+                (Some(a), Some(b), _) => self.cmp_component(i, a, b),
+                (Some(a), None, Missing::Zero) => a.cmp(&Component::default()),
+                (None, Some(b), Missing::Zero) => Component::default().cmp(b),
+                (Some(_), None, Missing::Less) | (None, Some(_), Missing::Greater) => std::cmp::Ordering::Greater,
+                (Some(_), None, Missing::Greater) | (None, Some(_), Missing::Less) => std::cmp::Ordering::Less,
+                (None, None, _) => std::cmp::Ordering::Equal,
+            };
+
+            if ord.is_ne() {
+                return ord
+            }
+        }
+
+        std::cmp::Ordering::Equal
+    }
+}
+
+impl PartialEq for Semver {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for Semver {}
+
+impl PartialOrd for Semver {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Semver {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp_components(other)
+            .then_with(|| self.rkind.cmp(&other.rkind))
+            .then_with(|| self.count.cmp(&other.count))
+    }
+}
+
+/// Displays a [`Semver`] according to some [`ParseOptions`]
+///
+/// Created by [`Semver::display_with`].
+pub struct Display<'a> {
+    semver: &'a Semver,
+    opts: &'a ParseOptions,
+}
+
+impl fmt::Display for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let semver = self.semver;
+
+        for (i, part) in semver.components.iter().enumerate() {
+            if i > 0 { write!(f, ".")?; }
+
+            // leading zeros are only kept when they matter
+            if semver.padding == Padding::Ignore {
+                write!(f, "{part}")?;
+            } else {
+                write!(f, "{}", part.digits())?;
+            }
+        }
+
+        match semver.rkind {
+            ReleaseKind::Dev    => write!(f, "-dev")?,
+            ReleaseKind::Pre    => write!(f, "-pre")?,
+            ReleaseKind::Next   => write!(f, "-next")?,
+            ReleaseKind::Alpha  => write!(f, "-alpha")?,
+            ReleaseKind::Beta   => write!(f, "-beta")?,
+            ReleaseKind::Rc     => write!(f, "-rc")?,
+            ReleaseKind::Patch  => write!(f, "p")?,
+            ReleaseKind::Stable => {},
+        };
+
+        if let Some(count) = semver.count {
+            if self.opts.charcount && let Some(ch) = char::from_u32(count as u32) {
+                write!(f, "{ch}")?;
+            } else {
+                write!(f, "{count}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Semver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_with(&ParseOptions::new()).fmt(f)
+    }
+}
+
+#[derive(Debug)]
+pub enum ParseSemverError {
+    UnrecognizedText,
+    MissingMajor,
+    /// A numeric component too large for a `u64`
+    Overflow(String),
+    /// A calendar version whose date doesn't exist
+    InvalidDate,
+    /// A line the [`Extract`] regex didn't match
+    NoMatch,
+}
+
+impl fmt::Display for ParseSemverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnrecognizedText => write!(f, "Unrecognized text"),
+            Self::MissingMajor => write!(f, "Missing major"),
+            Self::Overflow(part) => write!(f, "Numeric component {part} is too large"),
+            Self::InvalidDate => write!(f, "Invalid date"),
+            Self::NoMatch => write!(f, "Extraction regex did not match"),
+        }
+    }
+}
+
+impl std::error::Error for ParseSemverError {}
+
+fn recognized(s: &str, opts: &ParseOptions) -> bool {
+    if opts.charcount {
+        COUNT_IS_CHAR.is_match(s)
+    } else {
+        RECOGNIZED_RE.is_match(s)
+    }
+}
+
+impl Semver {
+    pub fn parse_with(naive: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        let mut s = naive.to_ascii_lowercase();
+
+        if let Some(idx) = s.find(|c: char| c.is_ascii_alphabetic()) {
+            if !recognized(&s, opts) && !opts.lenient {
+                return Err(ParseSemverError::UnrecognizedText)
+            }
+
+            // remove dot following the final character (e.g. 1.0.0-rc.1 -> 1.0.0-rc1)
+            if let Some(letter_idx) = s.rfind(|c: char| c.is_ascii_alphabetic())
+                && let Some(dot_idx) = s.rfind('.')
+                && dot_idx == letter_idx + 1
+            {
+                s.remove(dot_idx);
+            }
+
+            s.insert(idx, '.');
+        }
+
+        // remove dashes or underscores (e.g. 1.0.0-rc1 -> 1.0.0rc1)
+        let s = s.replace(['-',  '_'], "");
+
+        let mut parts = s.split('.');
+        let is_numeric = |p: &&str| !p.is_empty() && p.bytes().all(|c| c.is_ascii_digit());
+        let components = parts.clone()
+            .filter(is_numeric)
+            .map(|p| match p.parse::<u64>() {
+                Err(_) if !opts.arbitrary_precision => Err(ParseSemverError::Overflow(p.to_owned())),
+                _ => Ok(Component(p.to_owned())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if components.is_empty() {
+            return Err(ParseSemverError::MissingMajor)
+        }
+
+        let mut semver = Self { components, missing: opts.missing, padding: opts.padding, ..Default::default() };
+
+        if let Some(last_bit) = parts.next_back().filter(|p| !is_numeric(p)) {
+            if opts.charcount && let Some(caps) = COUNT_IS_CHAR.captures(&s) {
+                let m = caps.get(1).unwrap();
+                let ct = m.as_str().chars().next().unwrap() as u64;
+                semver.count = Some(ct);
+            } else {
+                vprint!(opts, "Matched {last_bit} to ");
+                semver.rkind = match &last_bit {
+                    s if RKIND_DEV.is_match(s) => ReleaseKind::Dev,
+                    s if RKIND_PRE.is_match(s) => ReleaseKind::Pre,
+                    s if RKIND_NEXT.is_match(s) => ReleaseKind::Next,
+                    s if RKIND_ALPHA.is_match(s) => ReleaseKind::Alpha,
+                    s if RKIND_BETA.is_match(s) => ReleaseKind::Beta,
+                    s if RKIND_RC.is_match(s) => ReleaseKind::Rc,
+                    s if RKIND_PATCH.is_match(s) => ReleaseKind::Patch,
+                    _ => ReleaseKind::Stable,
+                };
+                vprintln!(opts, "{:?}", semver.rkind);
+            }
+        }
+
+        if !matches!(semver.rkind, ReleaseKind::Stable)
+        && let Some(count) = s.rsplit_once(|c: char| c.is_ascii_alphabetic()).and_then(|ct| {
+            let ct = ct.1;
+            if ct.is_empty() { Some(1) } else { ct.parse::<u64>().ok() }
+        }) {
+            semver.count = Some(count);
+        }
+
+        vprintln!(opts, "Parsed semver '{}' from '{naive}'", semver.display_with(opts));
+        Ok(semver)
+    }
+
+    pub fn display_with<'a>(&'a self, opts: &'a ParseOptions) -> Display<'a> {
+        Display { semver: self, opts }
+    }
+}
+
+impl FromStr for Semver {
+    type Err = ParseSemverError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &ParseOptions::new())
+    }
+}
+
+/// Sorts `(payload, version)` pairs by their version, keeping equal versions in input order
+pub fn sort<T>(versions: &mut [(T, Version)]) {
+    versions.sort_by(|(_, a), (_, b)| a.cmp(b));
+}
+
+/// Like [`sort`], but newest first
+pub fn sort_reverse<T>(versions: &mut [(T, Version)]) {
+    versions.sort_by(|(_, a), (_, b)| b.cmp(a));
+}
+
+/// Whether `later` should replace `kept` as the representative of equal versions
+fn prefer_later(keep: Keep, later: &str, kept: &str) -> bool {
+    match keep {
+        Keep::First | Keep::Canonical => false,
+        Keep::Last => true,
+        Keep::Shortest => later.len() < kept.len(),
+    }
+}
+
+/// Collapses runs of `same` entries in sorted `entries`, keeping one according to `keep` and the
+/// entries' `text`
+fn dedup_with<E>(entries: &mut Vec<E>, keep: Keep, same: impl Fn(&E, &E) -> bool, text: impl Fn(&E) -> &str) {
+    // `later` is removed when this returns true, so swap it into `kept` to keep it instead
+    entries.dedup_by(|later, kept| {
+        if !same(later, kept) {
+            return false
+        }
+
+        if prefer_later(keep, text(later), text(kept)) {
+            std::mem::swap(later, kept);
+        }
+        true
+    });
+}
+
+/// Collapses runs of equal versions in sorted `versions`, keeping one according to `keep`
+pub fn dedup<T: AsRef<str>>(versions: &mut Vec<(T, Version)>, keep: Keep) {
+    dedup_with(versions, keep, |a, b| a.1 == b.1, |v| v.0.as_ref());
+}
+
+/// The value of a [`Key`] in some line
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Field {
+    Version(Version),
+    Text(String),
+}
+
+/// A parsed line, along with the values of any keys to order it by
+struct Parsed<S> {
+    line: S,
+    version: Version,
+    fields: Vec<Field>,
+}
+
+impl<S> Parsed<S> {
+    fn order(&self, other: &Self) -> std::cmp::Ordering {
+        self.fields.cmp(&other.fields).then_with(|| self.version.cmp(&other.version))
+    }
+}
+
+/// Parses the version and key fields of `line`
+fn parse_line<S: AsRef<str>>(line: S, opts: &SortOptions) -> Result<Parsed<S>, (S, ParseSemverError)> {
+    let text = line.as_ref();
+    let primary = opts.keys.iter().position(|k| !k.lexical);
+
+    // text that doesn't match the extraction regex has no version in it
+    let parse = |s: &str| {
+        let s = match &opts.extract {
+            Some(extract) => extract.find(s).ok_or(ParseSemverError::NoMatch)?,
+            None => s,
+        };
+        Version::parse_with(s, &opts.parse)
+    };
+
+    let version = match primary {
+        Some(i) => parse(opts.keys[i].extract(text, opts.separator)),
+        None => parse(text),
+    };
+
+    let fields = version.and_then(|version| {
+        let fields = opts.keys.iter().enumerate()
+            .map(|(i, key)| {
+                let field = key.extract(text, opts.separator);
+                if key.lexical {
+                    Ok(Field::Text(field.to_owned()))
+                } else if Some(i) == primary {
+                    Ok(Field::Version(version.clone()))
+                } else {
+                    parse(field).map(Field::Version)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok((version, fields))
+    });
+
+    match fields {
+        Ok((version, fields)) => Ok(Parsed { line, version, fields }),
+        Err(e) => Err((line, e)),
+    }
+}
+
+/// Parses and sorts `lines` according to `opts`
+///
+/// Blank lines are skipped. On failure, the offending line is returned alongside the error.
+pub fn sort_lines<S, I>(lines: I, opts: &SortOptions) -> Result<Vec<(S, Version)>, (S, ParseSemverError)>
+where
+    S: AsRef<str>,
+    I: IntoIterator<Item = S>,
+{
+    let parsed = lines.into_iter()
+        .filter(|line| !line.as_ref().trim().is_empty())
+        .filter_map(|line| {
+            match parse_line(line, opts) {
+                Ok(e) if !opts.kinds.contains(e.version.rkind()) => None,
+                Ok(e) if !opts.filters.iter().all(|f| f.matches(&e.version)) => None,
+                Ok(e) => Some(Ok(e)),
+                Err(_) if opts.ignore => None,
+                Err(e) => Some(Err(e)),
+            }
+        });
+
+    if let Some(select) = opts.select {
+        return select_from(parsed, select, opts)
+    }
+
+    let mut entries = parsed.collect::<Result<Vec<_>, _>>()?;
+
+    if opts.reverse {
+        entries.sort_by(|a, b| b.order(a));
+    } else {
+        entries.sort_by(|a, b| a.order(b));
+    }
+
+    if let Some(keep) = opts.unique {
+        dedup_with(&mut entries, keep, |a, b| a.order(b).is_eq(), |e| e.line.as_ref());
+    }
+
+    Ok(entries.into_iter().map(|e| (e.line, e.version)).collect())
+}
+
+/// Position of a version in the output order
+///
+/// `idx` breaks ties by input order, and is zero when equal versions are collapsed.
+struct SelectKey {
+    version: Version,
+    fields: Vec<Field>,
+    idx: usize,
+    reverse: bool,
+}
+
+impl PartialEq for SelectKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for SelectKey {}
+
+impl PartialOrd for SelectKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SelectKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let (a, b) = if self.reverse { (other, self) } else { (self, other) };
+        let ord = a.fields.cmp(&b.fields).then_with(|| a.version.cmp(&b.version));
+
+        ord.then_with(|| self.idx.cmp(&other.idx))
+    }
+}
+
+/// Selects versions in a single pass, holding at most `n` of them at a time
+fn select_from<S, I>(parsed: I, select: Select, opts: &SortOptions) -> Result<Vec<(S, Version)>, (S, ParseSemverError)>
+where
+    S: AsRef<str>,
+    I: Iterator<Item = Result<Parsed<S>, (S, ParseSemverError)>>,
+{
+    let mut kept = BTreeMap::new();
+
+    for (idx, result) in parsed.enumerate() {
+        let Parsed { line, version, fields } = result?;
+        let idx = if opts.unique.is_some() { 0 } else { idx };
+        let key = SelectKey { version, fields, idx, reverse: opts.reverse };
+
+        match kept.entry(key) {
+            Entry::Vacant(e) => { e.insert(line); },
+            Entry::Occupied(mut e) => {
+                if prefer_later(opts.unique.unwrap_or_default(), line.as_ref(), e.get().as_ref()) {
+                    e.insert(line);
+                }
+            },
+        }
+
+        match select {
+            Select::First(n) if kept.len() > n => { kept.pop_last(); },
+            Select::Last(n) if kept.len() > n => { kept.pop_first(); },
+            _ => {},
+        }
+    }
+
+    Ok(kept.into_iter().map(|(key, line)| (line, key.version)).collect())
+}
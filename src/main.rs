@@ -1,289 +1,443 @@
-use core::fmt;
+use std::env::args_os;
+use std::ffi::OsString;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
 
-use std::env::args;
-use std::io::{self, BufRead};
-use std::str::FromStr;
-use std::sync::atomic::{AtomicBool, Ordering::Relaxed as Lax};
-use std::sync::LazyLock;
+use versort::{Extract, Filter, Keep, Key, Kinds, Layout, Missing, NpmRange, Padding, ParseOptions, PerlForm, Range, ReleaseKind, Scheme, Select, SortOptions, VersionReq};
 
-use regex::Regex;
+macro_rules! die        { ($($arg:tt)*) => {{ eprintln!($($arg)*); std::process::exit(1); }}; }
+macro_rules! quit       { ($($arg:tt)*) => {{ println!($($arg)*); std::process::exit(0); }}; }
 
-static RECOGNIZED_RE:   LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"[0-9][-_\.]?(dev|pre|next|alpha|[^a-z]a|beta|[^a-z]b|r?c|patch|[^a-z]p)"#).expect("Invalid regex"));
-static COUNT_IS_CHAR:   LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"[^a-z]([a-z])$"#).expect("Invalid regex"));
+fn help() {
+    quit! {
+"\
+\x1b[4;1mUsage:\x1b[0;1m versort \x1b[0m[OPTIONS] [FILE]...
 
-static RKIND_DEV:       LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"dev"#).expect("Invalid regex"));
-static RKIND_PRE:       LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"pre"#).expect("Invalid regex"));
-static RKIND_NEXT:      LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"next"#).expect("Invalid regex"));
-static RKIND_ALPHA:     LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(alpha|a)([0-9]+)?$"#).expect("Invalid regex"));
-static RKIND_BETA:      LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(beta|b)([0-9]+)?$"#).expect("Invalid regex"));
-static RKIND_RC:        LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^r?c([0-9]+)?$"#).expect("Invalid regex"));
-static RKIND_PATCH:     LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(patch|p)([0-9]+)?$"#).expect("Invalid regex"));
+Sorts versions read from each FILE, or stdin if none are given or FILE is -.
 
-static VERBOSE:         AtomicBool      = AtomicBool::new(false);
-static FORMAT:          AtomicBool      = AtomicBool::new(false);
-static LENIENT:         AtomicBool      = AtomicBool::new(false);
-static IGNORE:          AtomicBool      = AtomicBool::new(false);
-static CHARCOUNT:       AtomicBool      = AtomicBool::new(false);
+\x1b[4;1mOptions:\x1b[0m
+    \x1b[1m-i | --ignore\x1b[0m       ignore versions that could not be parsed
+    \x1b[1m-f | --format\x1b[0m       format versions in output
+    \x1b[1m-l | --lenient\x1b[0m      parse versions more leniently
+    \x1b[1m-c | --charcount\x1b[0m    treat a single trailing character as a counter
+    \x1b[1m-s | --scheme SCHEME\x1b[0m
+                        how to parse and order versions: semver (default), semver2, pep440,
+                        debian, rpm, portage, perl or calver[:LAYOUT]
+                        (layout e.g. YYYY.0M.0D, YY.0M.MICRO, default YYYY.MM.DD)
+    \x1b[1m--perl-form FORM\x1b[0m    print perl versions as decimal (5.036000) or dotted (v5.36.0)
+                        with --format
+    \x1b[1m--calver-form LAYOUT\x1b[0m
+                        print calendar versions in another layout with --format
+    \x1b[1m--missing RULE\x1b[0m      where versions with fewer components sort: less (default, 1.0 < 1.0.0),
+                        greater (1.0.0 < 1.0) or zero (1 == 1.0 == 1.0.0)
+    \x1b[1m--missing-as-zero\x1b[0m   same as --missing zero
+    \x1b[1m--padding MODE\x1b[0m      how leading zeros sort: ignore (default, 001 == 1), padded-first
+                        (001 < 1) or fraction (1.10 < 1.9)
+    \x1b[1m--arbitrary-precision\x1b[0m
+                        accept numeric components too large for 64 bits
+    \x1b[1m--extract REGEX\x1b[0m     parse versions from the part of each line matched by REGEX, which is
+                        the group named v, or else the first group that matched, or else the
+                        whole match, printing whole lines
+    \x1b[1m-r | --reverse\x1b[0m      sort newest first
+    \x1b[1m-t | --field-separator SEP\x1b[0m
+                        split lines into fields at SEP (a single character, or \\t for a tab)
+                        instead of at whitespace
+    \x1b[1m-k | --key N[,M][l]\x1b[0m
+                        sort by fields N to M (or the end of the line), printing whole lines;
+                        keys are compared in order, as versions or, if suffixed with l, as
+                        text (repeatable)
+    \x1b[1m-u | --unique\x1b[0m       print only one of each equal version
+    \x1b[1m--keep WHICH\x1b[0m        which equal version to print: first, last, shortest or canonical
+    \x1b[1m--stable-only\x1b[0m       drop pre-releases, keeping only stable versions and patches
+    \x1b[1m--kinds KINDS\x1b[0m       keep only the comma-separated release kinds
+    \x1b[1m--min-kind KIND\x1b[0m     drop release kinds below KIND
+                        kinds: dev, pre, next, alpha, beta, rc, stable, patch
+    \x1b[1m--range RANGE\x1b[0m       keep only versions matching every comma-separated constraint
+                        (e.g. '>=2.7, <3.0'), with operators =, !=, <, <=, > and >=
+    \x1b[1m--where CONSTRAINT\x1b[0m  keep only versions matching a constraint (repeatable)
+    \x1b[1m--range-syntax SYNTAX\x1b[0m
+                        how to read --range and --where: plain (default) or npm
+                        (e.g. '^1.2.3 || 2.x', '1.2.3 - 2.3.4')
+    \x1b[1m--include-prerelease\x1b[0m
+                        let npm ranges match any pre-release
+    \x1b[1m--req REQ\x1b[0m           keep only versions matching a Cargo-style requirement
+                        (e.g. '^1.2', '~1.2.3', '1.*', '>=1.2, <1.5') (repeatable)
+    \x1b[1m--max\x1b[0m               print only the newest version
+    \x1b[1m--min\x1b[0m               print only the oldest version
+    \x1b[1m--first N\x1b[0m           print only the first N versions
+    \x1b[1m--last N\x1b[0m            print only the last N versions
+
+    \x1b[1m-z | --zero-terminated\x1b[0m
+                        read and print NUL-separated versions instead of lines
+    \x1b[1m--with-filename\x1b[0m     prefix each line with the file it came from
 
-macro_rules! die        { ($($arg:tt)*) => {{ eprintln!($($arg)*); std::process::exit(1); }}; }
-macro_rules! quit       { ($($arg:tt)*) => {{ println!($($arg)*); std::process::exit(0); }}; }
-macro_rules! vprint     { ($($arg:tt)*) => {{ if VERBOSE.load(Lax) { eprint!($($arg)*); } }}; }
-macro_rules! vprintln   { ($($arg:tt)*) => {{ if VERBOSE.load(Lax) { eprintln!($($arg)*); } }}; }
-
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
-pub enum ReleaseKind {
-    Dev,
-    Pre,
-    Next,
-    Alpha,
-    Beta,
-    Rc,
-    #[default]
-    Stable,
-    Patch,
+    \x1b[1m-v | --verbose\x1b[0m      print verbose messages to stderr
+    \x1b[1m-h | --help\x1b[0m         display help
+    \x1b[1m-V | --version\x1b[0m      display version
+
+\x1b[4;1mExamples:\x1b[0m
+    * \x1b[1mversort\x1b[0m < data.txt
+
+    * sed 's/^v//' data.txt | \x1b[1mversort\x1b[0m -lif
+
+    * git ls-remote --tags --refs https://github.com/tox-wtf/vagrant | shuf |
+          \x1b[1mversort\x1b[0m --extract '([^/]+)$'
+
+    * git ls-remote --tags --refs https://github.com/python/cpython | shuf |
+          \x1b[1mversort\x1b[0m -i --extract 'v?([^/]+)$' # ignore semvers that couldn't be parsed
+
+    * git ls-remote --tags --refs https://github.com/tmux/tmux | shuf |
+          \x1b[1mversort\x1b[0m -c --extract '([^/]+)$' # treat a single trailing character as a counter
+"
+    }
 }
 
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
-pub struct Semver {
-    pub major: u64,
-    pub minor: Option<u64>,
-    pub patch: Option<u64>,
-    pub ident: Option<u64>,
-    pub rkind: ReleaseKind,
-    pub count: Option<u64>,
+fn version() {
+    quit!("versort {}", env!("CARGO_PKG_VERSION"));
 }
 
-impl PartialOrd for Semver {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
+fn keep(which: &str) -> Keep {
+    match which {
+        "first" => Keep::First,
+        "last" => Keep::Last,
+        "shortest" => Keep::Shortest,
+        "canonical" => Keep::Canonical,
+        _ => die!("Unrecognized value for --keep: {which}"),
     }
 }
 
-impl Ord for Semver {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.major.cmp(&other.major)
-            .then_with(|| self.minor.cmp(&other.minor))
-            .then_with(|| self.patch.cmp(&other.patch))
-            .then_with(|| self.ident.cmp(&other.ident))
-            .then_with(|| self.rkind.cmp(&other.rkind))
-            .then_with(|| self.count.cmp(&other.count))
+fn missing(rule: &str) -> Missing {
+    match rule {
+        "less" => Missing::Less,
+        "greater" => Missing::Greater,
+        "zero" => Missing::Zero,
+        _ => die!("Unrecognized value for --missing: {rule}"),
     }
 }
 
-impl fmt::Display for Semver {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.major)?;
-        if let Some(part) = self.minor { write!(f, ".{part}")?; }
-        if let Some(part) = self.patch { write!(f, ".{part}")?; }
-        if let Some(part) = self.ident { write!(f, ".{part}")?; }
-
-        match self.rkind {
-            ReleaseKind::Dev    => write!(f, "-dev")?,
-            ReleaseKind::Pre    => write!(f, "-pre")?,
-            ReleaseKind::Next   => write!(f, "-next")?,
-            ReleaseKind::Alpha  => write!(f, "-alpha")?,
-            ReleaseKind::Beta   => write!(f, "-beta")?,
-            ReleaseKind::Rc     => write!(f, "-rc")?,
-            ReleaseKind::Patch  => write!(f, "p")?,
-            ReleaseKind::Stable => {},
-        };
-
-        if let Some(count) = self.count {
-            if CHARCOUNT.load(Lax) {
-                // SAFETY: `count` is derived from an ASCII alphabetic character
-                write!(f, "{}", unsafe { char::from_u32_unchecked(count as u32) })?;
-            } else {
-                write!(f, "{count}")?;
-            }
-        }
-
-        Ok(())
+fn padding(mode: &str) -> Padding {
+    match mode {
+        "ignore" => Padding::Ignore,
+        "padded-first" => Padding::PaddedFirst,
+        "fraction" => Padding::Fraction,
+        _ => die!("Unrecognized value for --padding: {mode}"),
     }
 }
 
-#[derive(Debug)]
-pub enum ParseSemverError {
-    UnrecognizedText,
-    MissingMajor,
+fn perl_form(form: &str) -> PerlForm {
+    match form {
+        "decimal" => PerlForm::Decimal,
+        "dotted" => PerlForm::Dotted,
+        _ => die!("Unrecognized value for --perl-form: {form}"),
+    }
 }
 
-impl fmt::Display for ParseSemverError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::UnrecognizedText => write!(f, "Unrecognized text"),
-            Self::MissingMajor => write!(f, "Missing major"),
-        }
-    }
+fn calver_layout(layout: &str) -> Layout {
+    layout.parse().unwrap_or_else(|e| die!("Invalid calver layout {layout}: {e}"))
 }
 
-fn recognized(s: &str) -> bool {
-    if CHARCOUNT.load(Lax) {
-        COUNT_IS_CHAR.is_match(s)
-    } else {
-        RECOGNIZED_RE.is_match(s)
+fn separator(sep: &str) -> char {
+    let mut chars = sep.chars();
+    match (sep, chars.next(), chars.next()) {
+        ("\\t", _, _) => '\t',
+        (_, Some(c), None) => c,
+        _ => die!("Field separator must be a single character: {sep}"),
     }
 }
 
-impl FromStr for Semver {
-    type Err = ParseSemverError;
+fn key(key: &str) -> Key {
+    key.parse().unwrap_or_else(|e| die!("Invalid key {key}: {e}"))
+}
 
-    fn from_str(naive: &str) -> Result<Self, Self::Err> {
-        let mut s = naive.to_ascii_lowercase();
+fn kind(arg: &str, kind: &str) -> ReleaseKind {
+    kind.parse().unwrap_or_else(|_| die!("Unrecognized release kind for {arg}: {kind}"))
+}
 
-        if let Some(idx) = s.find(|c: char| c.is_ascii_alphabetic()) {
-            if !recognized(&s) && !LENIENT.load(Lax) {
-                return Err(ParseSemverError::UnrecognizedText)
-            }
+fn value(arg: &str, args: &mut impl Iterator<Item = OsString>) -> String {
+    let value = args.next().unwrap_or_else(|| die!("Missing value for {arg}"));
+    value.into_string().unwrap_or_else(|v| die!("Invalid value for {arg}: {}", v.display()))
+}
 
-            // remove dot following the final character (e.g. 1.0.0-rc.1 -> 1.0.0-rc1)
-            if let Some(letter_idx) = s.rfind(|c: char| c.is_ascii_alphabetic())
-                && let Some(dot_idx) = s.rfind('.')
-                && dot_idx == letter_idx + 1
-            {
-                s.remove(dot_idx);
-            }
+fn parse_scheme(name: &str) -> Scheme {
+    match name {
+        "semver" => Scheme::Semver,
+        "pep440" => Scheme::Pep440,
+        "debian" => Scheme::Debian,
+        "rpm" => Scheme::Rpm,
+        "portage" => Scheme::Portage,
+        "semver2" => Scheme::Semver2,
+        "perl" => Scheme::Perl,
+        "calver" => Scheme::Calver(Layout::default()),
+        _ => match name.strip_prefix("calver:") {
+            Some(layout) => Scheme::Calver(calver_layout(layout)),
+            None => die!("Unrecognized scheme: {name}"),
+        },
+    }
+}
 
-            s.insert(idx, '.');
-        }
+fn count(arg: &str, n: &str) -> usize {
+    n.parse().unwrap_or_else(|_| die!("Invalid count for {arg}: {n}"))
+}
 
-        // remove dashes or underscores (e.g. 1.0.0-rc1 -> 1.0.0rc1)
-        let s = s.replace(['-',  '_'], "");
-
-        let mut parts = s.split('.');
-        let mut num_parts = parts.clone().filter_map(|p| p.parse::<u64>().ok());
-        let mut semver = Self {
-            major: num_parts.next().ok_or(ParseSemverError::MissingMajor)?,
-            minor: num_parts.next(),
-            patch: num_parts.next(),
-            ident: num_parts.next(),
-            ..Default::default()
-        };
+/// An input line, and the file it was read from
+///
+/// Lines that aren't valid UTF-8 are parsed with each non-ASCII byte read as a `.`, but keep their
+/// original bytes for output.
+struct Line<'a> {
+    file: &'a Path,
+    text: String,
+    raw: Option<Vec<u8>>,
+}
 
-        if let Some(last_bit) = parts.next_back().filter(|p| p.parse::<u64>().is_err()) {
-            if CHARCOUNT.load(Lax) && let Some(caps) = COUNT_IS_CHAR.captures(&s) {
-                let m = caps.get(1).unwrap();
-                let ct = m.as_str().chars().next().unwrap() as u64;
-                semver.count = Some(ct);
-            } else {
-                vprint!("Matched {last_bit} to ");
-                semver.rkind = match &last_bit {
-                    s if RKIND_DEV.is_match(s) => ReleaseKind::Dev,
-                    s if RKIND_PRE.is_match(s) => ReleaseKind::Pre,
-                    s if RKIND_NEXT.is_match(s) => ReleaseKind::Next,
-                    s if RKIND_ALPHA.is_match(s) => ReleaseKind::Alpha,
-                    s if RKIND_BETA.is_match(s) => ReleaseKind::Beta,
-                    s if RKIND_RC.is_match(s) => ReleaseKind::Rc,
-                    s if RKIND_PATCH.is_match(s) => ReleaseKind::Patch,
-                    _ => ReleaseKind::Stable,
-                };
-                vprintln!("{:?}", semver.rkind);
-            }
+impl<'a> Line<'a> {
+    fn new(file: &'a Path, record: Vec<u8>) -> Self {
+        match String::from_utf8(record) {
+            Ok(text) => Self { file, text, raw: None },
+            Err(e) => {
+                let raw = e.into_bytes();
+
+                // other bytes become separators, so the digits either side of them stay apart
+                let text = raw.iter().map(|&c| if c.is_ascii() { char::from(c) } else { '.' }).collect();
+                Self { file, text, raw: Some(raw) }
+            },
         }
+    }
 
-        if !matches!(semver.rkind, ReleaseKind::Stable)
-        && let Some(count) = s.rsplit_once(|c: char| c.is_ascii_alphabetic()).and_then(|ct| {
-            let ct = ct.1;
-            if ct.is_empty() { Some(1) } else { ct.parse::<u64>().ok() }
-        }) {
-            semver.count = Some(count);
-        }
+    fn bytes(&self) -> &[u8] {
+        self.raw.as_deref().unwrap_or(self.text.as_bytes())
+    }
 
-        vprintln!("Parsed semver '{semver}' from '{naive}'");
-        Ok(semver)
+    /// The file's name as it was given, which needn't be UTF-8
+    fn file_name(&self) -> &[u8] {
+        if self.file == Path::new("-") { b"(standard input)" } else { self.file.as_os_str().as_encoded_bytes() }
     }
 }
 
-fn help() {
-    quit! {
-"\
-\x1b[4;1mUsage:\x1b[0;1m versort \x1b[0m[OPTIONS]
-
-\x1b[4;1mOptions:\x1b[0m
-    \x1b[1m-i | --ignore\x1b[0m       ignore versions that could not be parsed
-    \x1b[1m-f | --format\x1b[0m       format versions in output
-    \x1b[1m-l | --lenient\x1b[0m      parse versions more leniently
-    \x1b[1m-c | --charcount\x1b[0m    treat a single trailing character as a counter
-
-    \x1b[1m-v | --verbose\x1b[0m      print verbose messages to stderr
-    \x1b[1m-h | --help\x1b[0m         display help
-    \x1b[1m-V | --version\x1b[0m      display version
-
-\x1b[4;1mExamples:\x1b[0m
-    * \x1b[1mversort\x1b[0m < data.txt
-
-    * sed 's/^v//' data.txt | \x1b[1mversort\x1b[0m -lif
-
-    * git ls-remote --tags --refs https://github.com/tox-wtf/vagrant |
-          sed 's,.*/,,' | shuf |
-          \x1b[1mversort\x1b[0m
+impl AsRef<str> for Line<'_> {
+    fn as_ref(&self) -> &str {
+        &self.text
+    }
+}
 
-    * git ls-remote --tags --refs https://github.com/python/cpython |
-          sed -e 's,.*/,,' -e 's,^v,,' | shuf |
-          \x1b[1mversort\x1b[0m -i # ignore semvers that couldn't be parsed
+/// Splits input into lines, or NUL-separated records if `zero`
+fn records(reader: impl BufRead, zero: bool) -> impl Iterator<Item = Vec<u8>> {
+    let sep = if zero { b'\0' } else { b'\n' };
 
-    * git ls-remote --tags --refs https://github.com/tmux/tmux |
-          sed -e 's,.*/,,' | shuf |
-          \x1b[1mversort\x1b[0m -c # treat a single trailing character as a counter
-"
-    }
+    reader.split(sep).map(|record| record.unwrap_or_else(|e| die!("Failed to read input: {e}"))).map(move |mut record| {
+        if !zero && record.last() == Some(&b'\r') {
+            record.pop();
+        }
+        record
+    })
 }
 
-fn version() {
-    quit!("versort {}", env!("CARGO_PKG_VERSION"));
+/// Extremes of the version order, resolved against `--reverse` once all flags are known
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum Extreme {
+    Max,
+    Min,
 }
 
 fn main() {
-    for arg in args().skip(1) {
-        if arg.starts_with("--") {
+    let mut format = false;
+    let mut parse = ParseOptions::new();
+    let mut scheme = Scheme::Semver;
+    let mut sort = SortOptions::new();
+    let mut unique = None;
+    let mut reverse = false;
+    let mut select = None;
+    let mut extreme = None;
+    let mut kinds = Kinds::all();
+    let mut ranges = Vec::new();
+    let mut reqs = Vec::new();
+    let mut npm = false;
+    let mut include_prerelease = false;
+    let mut with_filename = false;
+    let mut zero = false;
+    let mut files = Vec::new();
+
+    let mut args = args_os().skip(1);
+    while let Some(arg) = args.next() {
+        let arg = match arg.into_string() {
+            Ok(arg) => arg,
+            // only file names may be anything other than UTF-8
+            Err(file) => { files.push(PathBuf::from(file)); continue },
+        };
+
+        if arg == "--" {
+            files.extend(args.by_ref().map(PathBuf::from));
+        } else if arg.starts_with("--") {
             match arg.as_str() {
-                "--ignore" => IGNORE.store(true, Lax),
-                "--format" => FORMAT.store(true, Lax),
-                "--lenient" => LENIENT.store(true, Lax),
-                "--charcount" => CHARCOUNT.store(true, Lax),
-                "--verbose" => VERBOSE.store(true, Lax),
+                "--ignore" => sort = sort.ignore(true),
+                "--format" => format = true,
+                "--lenient" => parse = parse.lenient(true),
+                "--charcount" => parse = parse.charcount(true),
+                "--scheme" => scheme = parse_scheme(&value(&arg, &mut args)),
+                "--perl-form" => parse = parse.perl_form(Some(perl_form(&value(&arg, &mut args)))),
+                "--calver-form" => parse = parse.calver_form(Some(calver_layout(&value(&arg, &mut args)))),
+                "--missing" => parse = parse.missing(missing(&value(&arg, &mut args))),
+                "--missing-as-zero" => parse = parse.missing(Missing::Zero),
+                "--padding" => parse = parse.padding(padding(&value(&arg, &mut args))),
+                "--arbitrary-precision" => parse = parse.arbitrary_precision(true),
+                "--reverse" => reverse = true,
+                "--extract" => {
+                    let re = value(&arg, &mut args);
+                    let extract = Extract::new(&re).unwrap_or_else(|e| die!("Invalid regex for --extract: {e}"));
+                    sort = sort.extract(Some(extract));
+                },
+                "--field-separator" => sort = sort.separator(Some(separator(&value(&arg, &mut args)))),
+                "--key" => sort = sort.key(key(&value(&arg, &mut args))),
+                "--unique" => unique = unique.or(Some(Keep::First)),
+                "--keep" => {
+                    let which = value(&arg, &mut args);
+                    unique = Some(keep(&which));
+                },
+                "--stable-only" => kinds = kinds.intersect(Kinds::stable()),
+                "--kinds" => {
+                    let list = value(&arg, &mut args);
+                    kinds = kinds.intersect(list.split(',').map(|k| kind(&arg, k.trim())).collect());
+                },
+                "--min-kind" => {
+                    let min = value(&arg, &mut args);
+                    kinds = kinds.intersect(Kinds::at_least(kind(&arg, &min)));
+                },
+                "--range" | "--where" => {
+                    ranges.push(value(&arg, &mut args));
+                },
+                "--range-syntax" => {
+                    let syntax = value(&arg, &mut args);
+                    npm = match syntax.as_str() {
+                        "plain" => false,
+                        "npm" => true,
+                        _ => die!("Unrecognized range syntax: {syntax}"),
+                    };
+                },
+                "--include-prerelease" => include_prerelease = true,
+                "--req" => reqs.push(value(&arg, &mut args)),
+                "--max" => (select, extreme) = (None, Some(Extreme::Max)),
+                "--min" => (select, extreme) = (None, Some(Extreme::Min)),
+                "--first" | "--last" => {
+                    let n = value(&arg, &mut args);
+                    let n = count(&arg, &n);
+                    extreme = None;
+                    select = Some(if arg == "--first" { Select::First(n) } else { Select::Last(n) });
+                },
+                "--zero-terminated" => zero = true,
+                "--with-filename" => with_filename = true,
+                "--verbose" => parse = parse.verbose(true),
                 "--help" => help(),
                 "--version" => version(),
                 _ => die!("Unrecognized flag: {arg}"),
             }
         } else if arg.starts_with('-') && arg.len() > 1 {
-            for ch in arg.chars().skip(1) {
+            for (i, ch) in arg.char_indices().skip(1) {
+                // flags taking a value use the rest of the argument, or the next one
+                let rest = &arg[i + ch.len_utf8()..];
+                let mut value = || if rest.is_empty() { value(&arg, &mut args) } else { rest.to_owned() };
+
                 match ch {
-                    'i' => IGNORE.store(true, Lax),
-                    'f' => FORMAT.store(true, Lax),
-                    'l' => LENIENT.store(true, Lax),
-                    'c' => CHARCOUNT.store(true, Lax),
-                    'v' => VERBOSE.store(true, Lax),
+                    'i' => sort = sort.ignore(true),
+                    'f' => format = true,
+                    'l' => parse = parse.lenient(true),
+                    'c' => parse = parse.charcount(true),
+                    's' => { scheme = parse_scheme(&value()); break },
+                    't' => { sort = sort.separator(Some(separator(&value()))); break },
+                    'k' => { sort = sort.key(key(&value())); break },
+                    'r' => reverse = true,
+                    'u' => unique = unique.or(Some(Keep::First)),
+                    'z' => zero = true,
+                    'v' => parse = parse.verbose(true),
                     'h' => help(),
                     'V' => version(),
                     _ => die!("Unrecognized flag: {arg}")
                 }
             }
         } else {
-            die!("Unrecognized argument: {arg}")
+            files.push(PathBuf::from(arg));
         }
     }
 
-    let stdin = io::stdin();
-    let reader = stdin.lock();
-
-    let mut semvers = reader.lines()
-        .map_while(Result::ok)
-        .filter(|l| !l.trim().is_empty())
-        .filter_map(|v| {
-            match v.parse::<Semver>() {
-                Ok(s) => Some((v, s)),
-                Err(e) => {
-                    if IGNORE.load(Lax) { None }
-                    else { die!("Failed to parse {v} into a semver: {e}"); }
-                }
-            }
-        })
-        .collect::<Vec<_>>();
-    semvers.sort_by(|a, b| a.1.cmp(&b.1));
-
-    if FORMAT.load(Lax) {
-        println! { "{}", semvers.iter().map(|t| t.1.to_string()).collect::<Vec<_>>().join("\n") }
-    } else {
-        println! { "{}", semvers.iter().map(|t| t.0.clone()).collect::<Vec<_>>().join("\n") }
+    // canonical representatives are printed in their formatted form
+    if unique == Some(Keep::Canonical) {
+        format = true;
+    }
+
+    let select = match extreme {
+        Some(Extreme::Max) if reverse => Some(Select::First(1)),
+        Some(Extreme::Max) => Some(Select::Last(1)),
+        Some(Extreme::Min) if reverse => Some(Select::Last(1)),
+        Some(Extreme::Min) => Some(Select::First(1)),
+        None => select,
+    };
+
+    if scheme != Scheme::Semver && (npm || !reqs.is_empty()) {
+        die!("Requirements and npm ranges only work with the semver scheme");
     }
+
+    let parse = parse.scheme(scheme);
+
+    // constraints are parsed only now, so they honour flags given after them
+    let mut sort = sort.parse(parse).reverse(reverse).unique(unique).select(select).kinds(kinds);
+    for range in ranges {
+        let filter = if npm {
+            NpmRange::parse_with(&range, &parse, include_prerelease).map(Filter::Npm)
+        } else {
+            Range::parse_with(&range, &parse).map(Filter::Range)
+        };
+
+        sort = sort.filter(filter.unwrap_or_else(|e| die!("Failed to parse range {range}: {e}")));
+    }
+    for req in reqs {
+        let parsed = VersionReq::parse_with(&req, &parse)
+            .unwrap_or_else(|e| die!("Failed to parse requirement {req}: {e}"));
+        sort = sort.filter(Filter::Req(parsed));
+    }
+
+    if files.is_empty() {
+        files.push(PathBuf::from("-"));
+    }
+
+    // files are opened one at a time, as they're reached
+    let lines = files.iter().flat_map(|file| {
+        let reader: Box<dyn BufRead> = if file == Path::new("-") {
+            Box::new(io::stdin().lock())
+        } else {
+            Box::new(BufReader::new(File::open(file).unwrap_or_else(|e| die!("Failed to open {}: {e}", file.display()))))
+        };
+
+        records(reader, zero).map(move |record| Line::new(file, record))
+    });
+
+    let semvers = versort::sort_lines(lines, &sort)
+        .unwrap_or_else(|(v, e)| die!("Failed to parse {} into a version: {e}", String::from_utf8_lossy(v.bytes())));
+
+    // lines are written back as the exact bytes they were read as
+    let mut output = Vec::new();
+    for (i, (line, version)) in semvers.iter().enumerate() {
+        if i > 0 && !zero {
+            output.push(b'\n');
+        }
+
+        if with_filename {
+            output.extend_from_slice(line.file_name());
+            output.push(b':');
+        }
+
+        if format {
+            output.extend_from_slice(version.display_with(&parse).to_string().as_bytes());
+        } else {
+            output.extend_from_slice(line.bytes());
+        }
+
+        if zero {
+            output.push(b'\0');
+        }
+    }
+
+    if !zero {
+        output.push(b'\n');
+    }
+
+    io::stdout().lock().write_all(&output).unwrap_or_else(|e| die!("Failed to write output: {e}"));
 }
@@ -0,0 +1,315 @@
+use std::cmp::Ordering;
+
+use crate::{Op, ParseOptions, ParseSemverError, ReleaseKind, Semver, parse_pre};
+
+/// A full version that npm comparators are checked against
+///
+/// The lowest possible pre-release, written `-0` by npm, is `Dev` without a count.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub struct Bound {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub rkind: ReleaseKind,
+    pub count: Option<u64>,
+}
+
+impl Bound {
+    const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self { major, minor, patch, rkind: ReleaseKind::Stable, count: None }
+    }
+
+    /// The same version with the lowest possible pre-release (e.g. 2.0.0-0)
+    const fn lowest(self) -> Self {
+        Self { rkind: ReleaseKind::Dev, count: None, ..self }
+    }
+
+    /// The lowest pre-release if prereleases are included, to let them past lower bounds
+    const fn lower(self, include_prerelease: bool) -> Self {
+        if include_prerelease { self.lowest() } else { self }
+    }
+
+    fn of(semver: &Semver) -> Self {
+        Self {
+            major: semver.major(),
+            minor: semver.minor().unwrap_or(0),
+            patch: semver.patch().unwrap_or(0),
+            rkind: semver.rkind,
+            count: semver.count,
+        }
+    }
+
+    fn is_prerelease(&self) -> bool {
+        self.rkind < ReleaseKind::Stable
+    }
+}
+
+/// A primitive npm comparator, such as `>=1.2.3` or `<2.0.0-0`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct NpmComparator {
+    pub op: Op,
+    pub bound: Bound,
+}
+
+impl NpmComparator {
+    const fn new(op: Op, bound: Bound) -> Self {
+        Self { op, bound }
+    }
+
+    fn matches(&self, bound: &Bound) -> bool {
+        let ord = bound.cmp(&self.bound);
+        match self.op {
+            Op::Eq => ord == Ordering::Equal,
+            Op::Ne => ord != Ordering::Equal,
+            Op::Lt => ord == Ordering::Less,
+            Op::Le => ord != Ordering::Greater,
+            Op::Gt => ord == Ordering::Greater,
+            Op::Ge => ord != Ordering::Less,
+        }
+    }
+}
+
+/// A version as written in an npm range, where missing or `x`/`*` components are `None`
+#[derive(Debug, Clone, Copy)]
+struct Partial {
+    major: Option<u64>,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    rkind: ReleaseKind,
+    count: Option<u64>,
+}
+
+impl Partial {
+    fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        let s = s.trim().trim_start_matches(['v', 'V', '=']);
+
+        // build metadata never matters for matching
+        let s = s.split_once('+').map_or(s, |(s, _)| s);
+        if s.is_empty() {
+            return Ok(Self { major: None, minor: None, patch: None, rkind: ReleaseKind::Stable, count: None })
+        }
+
+        let split = s.find(|c: char| !matches!(c, '0'..='9' | '.' | '*' | 'x' | 'X')).unwrap_or(s.len());
+        let (nums, tail) = s.split_at(split);
+
+        let mut parts = nums.split('.');
+        let mut wildcard = false;
+        let mut component = |p: Option<&str>| {
+            match p {
+                None | Some("*" | "x" | "X") => { wildcard = true; Ok(None) },
+                Some("") => Err(ParseSemverError::MissingMajor),
+                // npm ignores anything after a wildcard (e.g. 1.x.3)
+                Some(_) if wildcard => Ok(None),
+                Some(p) => p.parse::<u64>().map(Some).map_err(|_| ParseSemverError::UnrecognizedText),
+            }
+        };
+
+        let mut partial = Self {
+            major: component(parts.next())?,
+            minor: component(parts.next())?,
+            patch: component(parts.next())?,
+            rkind: ReleaseKind::Stable,
+            count: None,
+        };
+
+        if !tail.is_empty() && partial.patch.is_some() {
+            (partial.rkind, partial.count) = parse_pre(s, tail, opts)?;
+        }
+
+        Ok(partial)
+    }
+
+    fn is_prerelease(&self) -> bool {
+        self.rkind < ReleaseKind::Stable
+    }
+
+    fn full(&self) -> Option<Bound> {
+        Some(Bound { rkind: self.rkind, count: self.count, ..Bound::new(self.major?, self.minor?, self.patch?) })
+    }
+}
+
+/// The first version past every `major.minor.patch`, where `None` matches any component
+///
+/// Overflow carries into the next component up, and there is no such version if the major overflows.
+fn after(major: u64, minor: Option<u64>, patch: Option<u64>) -> Option<Bound> {
+    match (minor, patch) {
+        (Some(minor), Some(patch)) => {
+            patch.checked_add(1).map(|p| Bound::new(major, minor, p)).or_else(|| after(major, Some(minor), None))
+        },
+        (Some(minor), None) => minor.checked_add(1).map(|m| Bound::new(major, m, 0)).or_else(|| after(major, None, None)),
+        (None, _) => major.checked_add(1).map(|m| Bound::new(m, 0, 0)),
+    }
+}
+
+/// `<hi-0`, or nothing if there is no upper bound
+fn below(hi: Option<Bound>) -> Option<NpmComparator> {
+    hi.map(|hi| NpmComparator::new(Op::Lt, hi.lowest()))
+}
+
+/// Desugars a comparator with an optional operator and a possibly partial version
+fn primitive(op: Option<Op>, v: Partial, incpre: bool) -> Vec<NpmComparator> {
+    use Op::*;
+
+    let Some(major) = v.major else {
+        // `<*` and `>*` can't match anything
+        return match op {
+            Some(Lt | Gt) => vec![NpmComparator::new(Lt, Bound::new(0, 0, 0).lowest())],
+            _ => vec![],
+        }
+    };
+
+    if let Some(full) = v.full() {
+        return vec![NpmComparator::new(op.unwrap_or(Eq), full)]
+    }
+
+    let (bump, lo) = (after(major, v.minor, None), Bound::new(major, v.minor.unwrap_or(0), 0));
+
+    match op {
+        Some(Gt) => match bump {
+            Some(bump) => vec![NpmComparator::new(Ge, bump.lower(incpre))],
+            // nothing is greater
+            None => vec![NpmComparator::new(Lt, Bound::new(0, 0, 0).lowest())],
+        },
+        Some(Ge) => vec![NpmComparator::new(Ge, lo.lower(incpre))],
+        Some(Lt) => vec![NpmComparator::new(Lt, lo.lowest())],
+        Some(Le) => below(bump).into_iter().collect(),
+        _ => std::iter::once(NpmComparator::new(Ge, lo.lower(incpre))).chain(below(bump)).collect(),
+    }
+}
+
+fn tilde(v: Partial) -> Vec<NpmComparator> {
+    let Some(major) = v.major else { return vec![] };
+
+    let (lo, hi) = match (v.minor, v.full()) {
+        (None, _) => (Bound::new(major, 0, 0), after(major, None, None)),
+        (Some(minor), None) => (Bound::new(major, minor, 0), after(major, Some(minor), None)),
+        (Some(minor), Some(full)) => (full, after(major, Some(minor), None)),
+    };
+
+    std::iter::once(NpmComparator::new(Op::Ge, lo)).chain(below(hi)).collect()
+}
+
+fn caret(v: Partial, incpre: bool) -> Vec<NpmComparator> {
+    let Some(major) = v.major else { return vec![] };
+
+    let (lo, hi) = match (v.minor, v.patch) {
+        (None, _) => (Bound::new(major, 0, 0), after(major, None, None)),
+        (Some(minor), None) if major == 0 => (Bound::new(0, minor, 0), after(0, Some(minor), None)),
+        (Some(minor), None) => (Bound::new(major, minor, 0), after(major, None, None)),
+        // the leftmost non-zero component is treated as the major
+        (Some(0), Some(patch)) if major == 0 => (Bound::new(0, 0, patch), after(0, Some(0), Some(patch))),
+        (Some(minor), Some(patch)) if major == 0 => (Bound::new(0, minor, patch), after(0, Some(minor), None)),
+        (Some(minor), Some(patch)) => (Bound::new(major, minor, patch), after(major, None, None)),
+    };
+
+    let lo = match v.full() {
+        Some(full) if v.is_prerelease() => full,
+        _ => lo.lower(incpre),
+    };
+
+    std::iter::once(NpmComparator::new(Op::Ge, lo)).chain(below(hi)).collect()
+}
+
+fn hyphen(from: Partial, to: Partial, incpre: bool) -> Vec<NpmComparator> {
+    let mut set = Vec::new();
+
+    if let Some(major) = from.major {
+        let lo = match (from.minor, from.full()) {
+            (None, _) => Bound::new(major, 0, 0).lower(incpre),
+            (Some(minor), None) => Bound::new(major, minor, 0).lower(incpre),
+            (_, Some(full)) if from.is_prerelease() => full,
+            (_, Some(full)) => full.lower(incpre),
+        };
+        set.push(NpmComparator::new(Op::Ge, lo));
+    }
+
+    if let Some(major) = to.major {
+        set.extend(match (to.minor, to.full()) {
+            (None, _) => below(after(major, None, None)),
+            (Some(minor), None) => below(after(major, Some(minor), None)),
+            (_, Some(full)) if to.is_prerelease() => Some(NpmComparator::new(Op::Le, full)),
+            (_, Some(full)) if incpre => below(after(full.major, Some(full.minor), Some(full.patch))),
+            (_, Some(full)) => Some(NpmComparator::new(Op::Le, full)),
+        });
+    }
+
+    set
+}
+
+fn comparator(token: &str, opts: &ParseOptions, incpre: bool) -> Result<Vec<NpmComparator>, ParseSemverError> {
+    // two-character operators must be checked first
+    let ops = [
+        ("~>", None), ("~", None), ("^", None),
+        (">=", Some(Op::Ge)), ("<=", Some(Op::Le)), (">", Some(Op::Gt)), ("<", Some(Op::Lt)), ("=", Some(Op::Eq)),
+    ];
+
+    for (prefix, op) in ops {
+        if let Some(rest) = token.strip_prefix(prefix) {
+            let v = Partial::parse_with(rest, opts)?;
+            return Ok(match prefix {
+                "~>" | "~" => tilde(v),
+                "^" => caret(v, incpre),
+                _ => primitive(op, v, incpre),
+            })
+        }
+    }
+
+    Ok(primitive(None, Partial::parse_with(token, opts)?, incpre))
+}
+
+fn comparator_set(s: &str, opts: &ParseOptions, incpre: bool) -> Result<Vec<NpmComparator>, ParseSemverError> {
+    let mut tokens = Vec::<String>::new();
+    let mut pending_op = false;
+
+    // glue operators to the version they precede (e.g. `>= 1.2` -> `>=1.2`)
+    for word in s.split_whitespace() {
+        if pending_op && let Some(last) = tokens.last_mut() {
+            last.push_str(word);
+        } else {
+            tokens.push(word.to_owned());
+        }
+        pending_op = word.chars().all(|c| matches!(c, '<' | '>' | '=' | '~' | '^'));
+    }
+
+    if let [from, dash, to] = tokens.as_slice() && dash == "-" {
+        return Ok(hyphen(Partial::parse_with(from, opts)?, Partial::parse_with(to, opts)?, incpre))
+    }
+
+    let mut set = Vec::new();
+    for token in &tokens {
+        set.extend(comparator(token, opts, incpre)?);
+    }
+    Ok(set)
+}
+
+/// An npm/node-semver range, such as `^1.2.3 || 2.x` or `1.2.3 - 2.3.4`
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct NpmRange {
+    /// Alternatives separated by `||`, each of which must be fully satisfied
+    pub sets: Vec<Vec<NpmComparator>>,
+    pub include_prerelease: bool,
+}
+
+impl NpmRange {
+    pub fn parse_with(s: &str, opts: &ParseOptions, include_prerelease: bool) -> Result<Self, ParseSemverError> {
+        let sets = s.split("||")
+            .map(|set| comparator_set(set, opts, include_prerelease))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { sets, include_prerelease })
+    }
+
+    pub fn matches(&self, semver: &Semver) -> bool {
+        let bound = Bound::of(semver);
+
+        self.sets.iter().any(|set| {
+            set.iter().all(|c| c.matches(&bound))
+                // pre-releases only match comparators on the same major.minor.patch that are also
+                // pre-releases, unless they're included
+                && (self.include_prerelease || !bound.is_prerelease() || set.iter().any(|c| {
+                    c.bound.is_prerelease()
+                        && (c.bound.major, c.bound.minor, c.bound.patch) == (bound.major, bound.minor, bound.patch)
+                }))
+        })
+    }
+}
@@ -0,0 +1,217 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{ParseSemverError, ReleaseKind};
+
+// adapted from the reference regex in the PEP 440 appendix
+static PEP440_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"(?xi)
+    ^\s*v?
+    (?:(?P<epoch>[0-9]+)!)?
+    (?P<release>[0-9]+(?:\.[0-9]+)*)
+    (?P<pre>
+        [-_\.]?
+        (?P<pre_l>alpha|a|beta|b|preview|pre|c|rc)
+        [-_\.]?
+        (?P<pre_n>[0-9]+)?
+    )?
+    (?P<post>
+        (?:-(?P<post_n1>[0-9]+))
+        |
+        (?:
+            [-_\.]?
+            (?P<post_l>post|rev|r)
+            [-_\.]?
+            (?P<post_n2>[0-9]+)?
+        )
+    )?
+    (?P<dev>
+        [-_\.]?
+        (?P<dev_l>dev)
+        [-_\.]?
+        (?P<dev_n>[0-9]+)?
+    )?
+    (?:\+(?P<local>[a-z0-9]+(?:[-_\.][a-z0-9]+)*))?
+    \s*$
+"#).expect("Invalid regex"));
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+pub enum PreLabel {
+    Alpha,
+    Beta,
+    Rc,
+}
+
+impl fmt::Display for PreLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Alpha => write!(f, "a"),
+            Self::Beta => write!(f, "b"),
+            Self::Rc => write!(f, "rc"),
+        }
+    }
+}
+
+/// A segment of a local version label, where numbers sort after letters
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+pub enum LocalPart {
+    Alpha(String),
+    Num(u64),
+}
+
+impl fmt::Display for LocalPart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Alpha(s) => write!(f, "{s}"),
+            Self::Num(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+/// A Python package version, as specified by PEP 440
+///
+/// Ordering follows the spec, so `1.0.dev1 < 1.0a1 < 1.0 < 1.0.post1`.
+#[derive(Debug, Default, Clone)]
+pub struct Pep440 {
+    pub epoch: u64,
+    pub release: Vec<u64>,
+    pub pre: Option<(PreLabel, u64)>,
+    pub post: Option<u64>,
+    pub dev: Option<u64>,
+    pub local: Vec<LocalPart>,
+}
+
+fn num(s: Option<regex::Match<'_>>) -> Result<u64, ParseSemverError> {
+    s.map_or(Ok(0), |m| m.as_str().parse().map_err(|_| ParseSemverError::UnrecognizedText))
+}
+
+impl Pep440 {
+    pub fn parse(s: &str) -> Result<Self, ParseSemverError> {
+        let caps = PEP440_RE.captures(s).ok_or(ParseSemverError::UnrecognizedText)?;
+
+        let release = caps["release"].split('.')
+            .map(|n| n.parse::<u64>().map_err(|_| ParseSemverError::UnrecognizedText))
+            .collect::<Result<_, _>>()?;
+
+        let pre = match caps.name("pre_l").map(|m| m.as_str().to_ascii_lowercase()) {
+            Some(l) => {
+                let label = match l.as_str() {
+                    "a" | "alpha" => PreLabel::Alpha,
+                    "b" | "beta" => PreLabel::Beta,
+                    _ => PreLabel::Rc,
+                };
+                Some((label, num(caps.name("pre_n"))?))
+            },
+            None => None,
+        };
+
+        let post = match caps.name("post") {
+            Some(_) => Some(num(caps.name("post_n1").or(caps.name("post_n2")))?),
+            None => None,
+        };
+
+        let dev = match caps.name("dev") {
+            Some(_) => Some(num(caps.name("dev_n"))?),
+            None => None,
+        };
+
+        let local = caps.name("local").map_or(Vec::new(), |m| {
+            m.as_str().split(['-', '_', '.'])
+                .map(|part| match part.parse::<u64>() {
+                    Ok(n) if part.bytes().all(|b| b.is_ascii_digit()) => LocalPart::Num(n),
+                    _ => LocalPart::Alpha(part.to_ascii_lowercase()),
+                })
+                .collect()
+        });
+
+        Ok(Self { epoch: num(caps.name("epoch"))?, release, pre, post, dev, local })
+    }
+
+    /// The closest [`ReleaseKind`], for filtering
+    pub fn rkind(&self) -> ReleaseKind {
+        match (self.pre, self.post, self.dev) {
+            (Some((PreLabel::Alpha, _)), ..) => ReleaseKind::Alpha,
+            (Some((PreLabel::Beta, _)), ..) => ReleaseKind::Beta,
+            (Some((PreLabel::Rc, _)), ..) => ReleaseKind::Rc,
+            (None, _, Some(_)) => ReleaseKind::Dev,
+            (None, Some(_), None) => ReleaseKind::Patch,
+            (None, None, None) => ReleaseKind::Stable,
+        }
+    }
+
+    /// The release segment without trailing zeros, since 1.0 == 1.0.0
+    fn release_key(&self) -> &[u64] {
+        let len = self.release.iter().rposition(|&n| n != 0).map_or(0, |i| i + 1);
+        &self.release[..len]
+    }
+}
+
+impl PartialEq for Pep440 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pep440 {}
+
+impl PartialOrd for Pep440 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pep440 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // a bare dev release (1.0.dev1) sorts before any pre-release of the same version, while a
+        // final release sorts after them; `None` stands in for the former, `Some(None)` the latter
+        fn pre_key(v: &Pep440) -> Option<Option<(PreLabel, u64)>> {
+            match (v.pre, v.post, v.dev) {
+                (None, None, Some(_)) => None,
+                (None, ..) => Some(None),
+                (Some(pre), ..) => Some(Some(pre)),
+            }
+        }
+
+        // `None` sorts first, so it's used for post releases and last for dev releases
+        fn dev_key(v: &Pep440) -> (bool, u64) {
+            v.dev.map_or((true, 0), |dev| (false, dev))
+        }
+
+        self.epoch.cmp(&other.epoch)
+            .then_with(|| self.release_key().cmp(other.release_key()))
+            .then_with(|| match (pre_key(self), pre_key(other)) {
+                (Some(None), Some(Some(_))) => Ordering::Greater,
+                (Some(Some(_)), Some(None)) => Ordering::Less,
+                (a, b) => a.cmp(&b),
+            })
+            .then_with(|| self.post.cmp(&other.post))
+            .then_with(|| dev_key(self).cmp(&dev_key(other)))
+            .then_with(|| self.local.cmp(&other.local))
+    }
+}
+
+impl fmt::Display for Pep440 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.epoch != 0 {
+            write!(f, "{}!", self.epoch)?;
+        }
+
+        for (i, n) in self.release.iter().enumerate() {
+            if i > 0 { write!(f, ".")?; }
+            write!(f, "{n}")?;
+        }
+
+        if let Some((label, n)) = self.pre { write!(f, "{label}{n}")?; }
+        if let Some(n) = self.post { write!(f, ".post{n}")?; }
+        if let Some(n) = self.dev { write!(f, ".dev{n}")?; }
+
+        for (i, part) in self.local.iter().enumerate() {
+            write!(f, "{}{part}", if i == 0 { '+' } else { '.' })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{ParseSemverError, ReleaseKind};
+
+static DECIMAL_RE:      LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^[0-9]+(?:\.[0-9]+(?:_[0-9]+)?)?$"#).expect("Invalid regex"));
+static DOTTED_RE:       LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(?:v[0-9]+(?:\.[0-9]+)*|[0-9]+(?:\.[0-9]+){2,})(?:_[0-9]+)?$"#).expect("Invalid regex"));
+
+/// How a [`Perl`] version is written
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum PerlForm {
+    /// A decimal number, such as `5.036000`
+    #[default]
+    Decimal,
+    /// A dotted version string, such as `v5.36.0`
+    Dotted,
+}
+
+/// A Perl module version, in either decimal or dotted form
+///
+/// Both forms are normalised to dotted components, where each group of three decimal places is
+/// one component, so `5.036000 == v5.36.0` and `1.10 < 1.9`. Missing components count as zero.
+#[derive(Debug, Default, Clone)]
+pub struct Perl {
+    pub components: Vec<u64>,
+    /// The form the version was written in
+    pub form: PerlForm,
+    /// Whether this is an underscored alpha release (e.g. 1.23_01), which doesn't affect ordering
+    pub alpha: bool,
+}
+
+impl Perl {
+    pub fn parse(s: &str) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+
+        if !s.starts_with(|c: char| c.is_ascii_digit() || c == 'v') {
+            return Err(ParseSemverError::MissingMajor)
+        }
+
+        let form = if DOTTED_RE.is_match(s) {
+            PerlForm::Dotted
+        } else if DECIMAL_RE.is_match(s) {
+            PerlForm::Decimal
+        } else {
+            return Err(ParseSemverError::UnrecognizedText)
+        };
+
+        let alpha = s.contains('_');
+        let digits = s.trim_start_matches('v').replace('_', "");
+        let parse_num = |n: &str| n.parse::<u64>().map_err(|_| ParseSemverError::Overflow(n.to_owned()));
+
+        let components = match form {
+            PerlForm::Dotted => digits.split('.').map(parse_num).collect::<Result<_, _>>()?,
+            PerlForm::Decimal => {
+                let (int, frac) = digits.split_once('.').unwrap_or((&digits, ""));
+
+                // every three decimal places make a component, padding the last with zeros
+                let mut frac = frac.to_owned();
+                while frac.len() % 3 != 0 {
+                    frac.push('0');
+                }
+
+                std::iter::once(int)
+                    .chain(frac.as_bytes().chunks(3).map(|c| std::str::from_utf8(c).unwrap_or_default()))
+                    .map(parse_num)
+                    .collect::<Result<_, _>>()?
+            },
+        };
+
+        Ok(Self { components, form, alpha })
+    }
+
+    /// The closest [`ReleaseKind`], for filtering
+    pub const fn rkind(&self) -> ReleaseKind {
+        if self.alpha { ReleaseKind::Alpha } else { ReleaseKind::Stable }
+    }
+
+    /// Displays the version in `form`, rather than the form it was written in
+    pub fn display_as(&self, form: PerlForm) -> impl fmt::Display + '_ {
+        fmt::from_fn(move |f| match form {
+            PerlForm::Decimal => {
+                write!(f, "{}.", self.components[0])?;
+
+                if self.components.len() == 1 {
+                    write!(f, "000")?;
+                }
+
+                for part in &self.components[1..] {
+                    write!(f, "{part:03}")?;
+                }
+
+                Ok(())
+            },
+            PerlForm::Dotted => {
+                write!(f, "v{}", self.components[0])?;
+
+                // dotted versions have at least three components
+                for i in 1..self.components.len().max(3) {
+                    write!(f, ".{}", self.components.get(i).copied().unwrap_or(0))?;
+                }
+
+                Ok(())
+            },
+        })
+    }
+}
+
+impl PartialEq for Perl {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Perl {}
+
+impl PartialOrd for Perl {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Perl {
+    fn cmp(&self, other: &Self) -> Ordering {
+        for i in 0..self.components.len().max(other.components.len()) {
+            let a = self.components.get(i).copied().unwrap_or(0);
+            let b = other.components.get(i).copied().unwrap_or(0);
+
+            if a != b {
+                return a.cmp(&b)
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl fmt::Display for Perl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_as(self.form))
+    }
+}
@@ -0,0 +1,179 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{ParseOptions, ParseSemverError, ReleaseKind};
+
+static PORTAGE_RE:      LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^([0-9]+(?:\.[0-9]+)*)([a-z])?((?:_(?:alpha|beta|pre|rc|p)[0-9]*)*)(?:-r([0-9]+))?$"#).expect("Invalid regex"));
+static PORTAGE_LAX_RE:  LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^([0-9]+(?:\.[0-9]+)*)([a-z])?((?:[-_](?:alpha|beta|pre|rc|p)[0-9]*)*)(?:-r([0-9]+))?$"#).expect("Invalid regex"));
+static SUFFIX_RE:       LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"[-_](alpha|beta|pre|rc|p)([0-9]*)"#).expect("Invalid regex"));
+
+/// A Gentoo package version, as specified by the Package Manager Specification
+///
+/// Suffixes are stored as [`ReleaseKind`]s, with `_p` being [`ReleaseKind::Patch`], and ordered
+/// `_alpha < _beta < _pre < _rc < (none) < _p`.
+#[derive(Debug, Default, Clone)]
+pub struct Portage {
+    /// Numeric components, kept as digit strings since leading zeros matter past the first
+    pub numbers: Vec<String>,
+    pub letter: Option<char>,
+    pub suffixes: Vec<(ReleaseKind, Option<u64>)>,
+    pub revision: Option<u64>,
+}
+
+fn int_cmp(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Where a suffix sorts relative to having no suffix at all
+const fn suffix_rank(kind: ReleaseKind) -> u8 {
+    match kind {
+        ReleaseKind::Alpha => 0,
+        ReleaseKind::Beta => 1,
+        ReleaseKind::Pre => 2,
+        ReleaseKind::Rc => 3,
+        ReleaseKind::Patch => 5,
+        _ => 4,
+    }
+}
+
+impl Portage {
+    /// Parses a version, accepting hyphens before suffixes (e.g. 1.0-rc1) if lenient
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+        let re = if opts.lenient { &PORTAGE_LAX_RE } else { &PORTAGE_RE };
+
+        let caps = re.captures(s).ok_or(if s.starts_with(|c: char| c.is_ascii_digit()) {
+            ParseSemverError::UnrecognizedText
+        } else {
+            ParseSemverError::MissingMajor
+        })?;
+
+        let parse_num = |n: &str| n.parse::<u64>().map_err(|_| ParseSemverError::UnrecognizedText);
+
+        let suffixes = SUFFIX_RE.captures_iter(caps.get(3).map_or("", |m| m.as_str()))
+            .map(|suffix| {
+                let kind = match &suffix[1] {
+                    "alpha" => ReleaseKind::Alpha,
+                    "beta" => ReleaseKind::Beta,
+                    "pre" => ReleaseKind::Pre,
+                    "rc" => ReleaseKind::Rc,
+                    _ => ReleaseKind::Patch,
+                };
+                let count = match &suffix[2] {
+                    "" => None,
+                    n => Some(parse_num(n)?),
+                };
+                Ok((kind, count))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            numbers: caps[1].split('.').map(str::to_owned).collect(),
+            letter: caps.get(2).and_then(|m| m.as_str().chars().next()),
+            suffixes,
+            revision: caps.get(4).map(|m| parse_num(m.as_str())).transpose()?,
+        })
+    }
+
+    /// The closest [`ReleaseKind`], for filtering, which is that of the first suffix
+    pub fn rkind(&self) -> ReleaseKind {
+        self.suffixes.first().map_or(ReleaseKind::Stable, |(kind, _)| *kind)
+    }
+
+    fn cmp_numbers(&self, other: &Self) -> Ordering {
+        let mut ord = int_cmp(&self.numbers[0], &other.numbers[0]);
+
+        for (a, b) in self.numbers.iter().zip(&other.numbers).skip(1) {
+            if ord.is_ne() {
+                return ord
+            }
+
+            // components with leading zeros compare like decimal fractions
+            ord = if a.starts_with('0') || b.starts_with('0') {
+                a.trim_end_matches('0').cmp(b.trim_end_matches('0'))
+            } else {
+                int_cmp(a, b)
+            };
+        }
+
+        ord.then_with(|| self.numbers.len().cmp(&other.numbers.len()))
+    }
+
+    fn cmp_suffixes(&self, other: &Self) -> Ordering {
+        for i in 0..self.suffixes.len().max(other.suffixes.len()) {
+            // an extra suffix is only newer than none at all if it's a patch
+            let ord = match (self.suffixes.get(i), other.suffixes.get(i)) {
+                (Some((a, an)), Some((b, bn))) => {
+                    suffix_rank(*a).cmp(&suffix_rank(*b)).then_with(|| an.unwrap_or(0).cmp(&bn.unwrap_or(0)))
+                },
+                (Some((a, _)), None) => suffix_rank(*a).cmp(&suffix_rank(ReleaseKind::Stable)),
+                (None, Some((b, _))) => suffix_rank(ReleaseKind::Stable).cmp(&suffix_rank(*b)),
+                (None, None) => Ordering::Equal,
+            };
+
+            if ord.is_ne() {
+                return ord
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl PartialEq for Portage {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Portage {}
+
+impl PartialOrd for Portage {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Portage {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_numbers(other)
+            .then_with(|| self.letter.cmp(&other.letter))
+            .then_with(|| self.cmp_suffixes(other))
+            .then_with(|| self.revision.unwrap_or(0).cmp(&other.revision.unwrap_or(0)))
+    }
+}
+
+impl fmt::Display for Portage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.numbers.join("."))?;
+
+        if let Some(letter) = self.letter {
+            write!(f, "{letter}")?;
+        }
+
+        for (kind, count) in &self.suffixes {
+            match kind {
+                ReleaseKind::Alpha => write!(f, "_alpha")?,
+                ReleaseKind::Beta => write!(f, "_beta")?,
+                ReleaseKind::Pre => write!(f, "_pre")?,
+                ReleaseKind::Rc => write!(f, "_rc")?,
+                _ => write!(f, "_p")?,
+            }
+
+            if let Some(count) = count {
+                write!(f, "{count}")?;
+            }
+        }
+
+        if let Some(revision) = self.revision {
+            write!(f, "-r{revision}")?;
+        }
+
+        Ok(())
+    }
+}
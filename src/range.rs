@@ -0,0 +1,106 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+
+use crate::{ParseOptions, ParseSemverError, Version};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Op {
+    const fn accepts(self, ord: Ordering) -> bool {
+        match self {
+            Self::Eq => ord.is_eq(),
+            Self::Ne => ord.is_ne(),
+            Self::Lt => ord.is_lt(),
+            Self::Le => ord.is_le(),
+            Self::Gt => ord.is_gt(),
+            Self::Ge => ord.is_ge(),
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Eq => write!(f, "="),
+            Self::Ne => write!(f, "!="),
+            Self::Lt => write!(f, "<"),
+            Self::Le => write!(f, "<="),
+            Self::Gt => write!(f, ">"),
+            Self::Ge => write!(f, ">="),
+        }
+    }
+}
+
+/// A single comparison against a version, such as `>=2.7`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Constraint {
+    pub op: Op,
+    pub version: Version,
+}
+
+impl Constraint {
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+
+        // two-character operators must be checked first
+        let (op, operand) = [
+            ("==", Op::Eq), ("!=", Op::Ne), ("<=", Op::Le), (">=", Op::Ge),
+            ("=", Op::Eq), ("<", Op::Lt), (">", Op::Gt),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| s.strip_prefix(prefix).map(|rest| (op, rest)))
+        .unwrap_or((Op::Eq, s));
+
+        Ok(Self { op, version: Version::parse_with(operand.trim(), opts)? })
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        self.op.accepts(version.cmp(&self.version))
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.op, self.version)
+    }
+}
+
+/// Comma-separated constraints that must all hold, such as `>=2.7, <3.0`
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Range {
+    pub constraints: Vec<Constraint>,
+}
+
+impl Range {
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        let constraints = s.split(',')
+            .map(|c| Constraint::parse_with(c, opts))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { constraints })
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        self.constraints.iter().all(|c| c.matches(version))
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.constraints.iter().enumerate() {
+            if i > 0 { write!(f, ", ")?; }
+            write!(f, "{c}")?;
+        }
+
+        Ok(())
+    }
+}
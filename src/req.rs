@@ -0,0 +1,231 @@
+use crate::{ParseOptions, ParseSemverError, ReleaseKind, Semver, parse_pre};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ReqOp {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+    Wildcard,
+}
+
+/// A single Cargo-style comparator, such as `^1.2` or `>=1.0.0-rc.1`
+///
+/// Missing components are left as `None` so that partial versions keep their meaning (e.g. `~1`
+/// allows any minor, while `~1.0` doesn't).
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Comparator {
+    pub op: ReqOp,
+    pub major: u64,
+    pub minor: Option<u64>,
+    pub patch: Option<u64>,
+    pub rkind: ReleaseKind,
+    pub count: Option<u64>,
+}
+
+/// The pre-release part of a version, which is empty for stable versions
+type Pre = (ReleaseKind, Option<u64>);
+
+fn pre(semver: &Semver) -> Pre {
+    (semver.rkind, semver.count)
+}
+
+impl Comparator {
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+
+        // two-character operators must be checked first
+        let (op, rest) = [
+            (">=", ReqOp::GreaterEq), ("<=", ReqOp::LessEq),
+            ("=", ReqOp::Exact), (">", ReqOp::Greater), ("<", ReqOp::Less),
+            ("~", ReqOp::Tilde), ("^", ReqOp::Caret),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| s.strip_prefix(prefix).map(|rest| (Some(op), rest.trim_start())))
+        .unwrap_or((None, s));
+
+        let split = rest.find(|c: char| !matches!(c, '0'..='9' | '.' | '*' | 'x' | 'X')).unwrap_or(rest.len());
+        let (nums, tail) = rest.split_at(split);
+
+        let mut parts = nums.split('.');
+        let major = parts.next().filter(|p| !p.is_empty()).ok_or(ParseSemverError::MissingMajor)?;
+        let major = major.parse::<u64>().map_err(|_| ParseSemverError::UnrecognizedText)?;
+
+        let mut wildcard = false;
+        let mut component = |p: Option<&str>| {
+            match p {
+                None => Ok(None),
+                Some("*" | "x" | "X") => { wildcard = true; Ok(None) },
+                // nothing may follow a wildcard (e.g. 1.*.3)
+                Some(_) if wildcard => Err(ParseSemverError::UnrecognizedText),
+                Some(p) => p.parse::<u64>().map(Some).map_err(|_| ParseSemverError::UnrecognizedText),
+            }
+        };
+
+        let minor = component(parts.next())?;
+        let patch = component(parts.next())?;
+        if parts.next().is_some() {
+            return Err(ParseSemverError::UnrecognizedText)
+        }
+
+        let mut cmp = Self {
+            op: op.unwrap_or(if wildcard { ReqOp::Wildcard } else { ReqOp::Caret }),
+            major,
+            minor,
+            patch,
+            rkind: ReleaseKind::Stable,
+            count: None,
+        };
+
+        if !tail.is_empty() {
+            if wildcard {
+                return Err(ParseSemverError::UnrecognizedText)
+            }
+
+            (cmp.rkind, cmp.count) = parse_pre(rest, tail, opts)?;
+        }
+
+        Ok(cmp)
+    }
+
+    fn pre(&self) -> Pre {
+        (self.rkind, self.count)
+    }
+
+    pub fn matches(&self, semver: &Semver) -> bool {
+        match self.op {
+            ReqOp::Exact | ReqOp::Wildcard => self.matches_exact(semver),
+            ReqOp::Greater => self.matches_greater(semver),
+            ReqOp::GreaterEq => self.matches_exact(semver) || self.matches_greater(semver),
+            ReqOp::Less => self.matches_less(semver),
+            ReqOp::LessEq => self.matches_exact(semver) || self.matches_less(semver),
+            ReqOp::Tilde => self.matches_tilde(semver),
+            ReqOp::Caret => self.matches_caret(semver),
+        }
+    }
+
+    fn matches_exact(&self, semver: &Semver) -> bool {
+        let (minor, patch) = (semver.minor().unwrap_or(0), semver.patch().unwrap_or(0));
+
+        semver.major() == self.major
+            && self.minor.is_none_or(|m| m == minor)
+            && self.patch.is_none_or(|p| p == patch)
+            && pre(semver) == self.pre()
+    }
+
+    fn matches_greater(&self, semver: &Semver) -> bool {
+        let (minor, patch) = (semver.minor().unwrap_or(0), semver.patch().unwrap_or(0));
+
+        if semver.major() != self.major {
+            return semver.major() > self.major
+        }
+        let Some(m) = self.minor else { return false };
+        if minor != m {
+            return minor > m
+        }
+        let Some(p) = self.patch else { return false };
+        if patch != p {
+            return patch > p
+        }
+        pre(semver) > self.pre()
+    }
+
+    fn matches_less(&self, semver: &Semver) -> bool {
+        let (minor, patch) = (semver.minor().unwrap_or(0), semver.patch().unwrap_or(0));
+
+        if semver.major() != self.major {
+            return semver.major() < self.major
+        }
+        let Some(m) = self.minor else { return false };
+        if minor != m {
+            return minor < m
+        }
+        let Some(p) = self.patch else { return false };
+        if patch != p {
+            return patch < p
+        }
+        pre(semver) < self.pre()
+    }
+
+    fn matches_tilde(&self, semver: &Semver) -> bool {
+        let (minor, patch) = (semver.minor().unwrap_or(0), semver.patch().unwrap_or(0));
+
+        if semver.major() != self.major {
+            return false
+        }
+        if self.minor.is_some_and(|m| m != minor) {
+            return false
+        }
+        if let Some(p) = self.patch && p != patch {
+            return patch > p
+        }
+        pre(semver) >= self.pre()
+    }
+
+    fn matches_caret(&self, semver: &Semver) -> bool {
+        let (minor, patch) = (semver.minor().unwrap_or(0), semver.patch().unwrap_or(0));
+
+        if semver.major() != self.major {
+            return false
+        }
+        let Some(m) = self.minor else { return true };
+        let Some(p) = self.patch else {
+            return if self.major > 0 { minor >= m } else { minor == m }
+        };
+
+        // 0.x versions treat the leftmost non-zero component as the major
+        if self.major > 0 {
+            if minor != m {
+                return minor > m
+            } else if patch != p {
+                return patch > p
+            }
+        } else if m > 0 {
+            if minor != m {
+                return false
+            } else if patch != p {
+                return patch > p
+            }
+        } else if minor != m || patch != p {
+            return false
+        }
+
+        pre(semver) >= self.pre()
+    }
+
+    /// Whether a pre-release of `semver` may match, which needs the same major.minor.patch
+    fn allows_pre(&self, semver: &Semver) -> bool {
+        self.major == semver.major()
+            && self.minor == Some(semver.minor().unwrap_or(0))
+            && self.patch == Some(semver.patch().unwrap_or(0))
+            && self.rkind < ReleaseKind::Stable
+    }
+}
+
+/// Comma-separated Cargo-style comparators that must all hold, such as `^1.2, <1.8`
+///
+/// Components past the patch are ignored.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct VersionReq {
+    pub comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        let comparators = s.split(',')
+            .map(str::trim)
+            .filter(|c| !matches!(*c, "*" | "x" | "X"))
+            .map(|c| Comparator::parse_with(c, opts))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { comparators })
+    }
+
+    pub fn matches(&self, semver: &Semver) -> bool {
+        self.comparators.iter().all(|c| c.matches(semver))
+            && (semver.rkind >= ReleaseKind::Stable || self.comparators.iter().any(|c| c.allows_pre(semver)))
+    }
+}
@@ -0,0 +1,170 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+
+use crate::{ParseSemverError, ReleaseKind, guess_kind};
+
+/// An RPM package version, `[epoch:]version[-release]`
+///
+/// Ordering follows `rpmvercmp()`, so `~` marks a pre-release (`1.0~rc1 < 1.0`) and `^` a
+/// post-release snapshot (`1.0 < 1.0^git1 < 1.0.1`). A missing release sorts before any release.
+#[derive(Debug, Default, Clone)]
+pub struct Rpm {
+    pub epoch: Option<u64>,
+    pub version: String,
+    pub release: Option<String>,
+}
+
+impl Rpm {
+    pub fn parse(s: &str) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+
+        let (epoch, rest) = match s.split_once(':') {
+            Some((epoch, rest)) => {
+                let epoch = epoch.parse::<u64>().map_err(|_| ParseSemverError::UnrecognizedText)?;
+                (Some(epoch), rest)
+            },
+            None => (None, s),
+        };
+
+        let (version, release) = match rest.rsplit_once('-') {
+            Some((version, release)) => (version, Some(release)),
+            None => (rest, None),
+        };
+
+        if version.is_empty() || release.is_some_and(str::is_empty) {
+            return Err(ParseSemverError::MissingMajor)
+        }
+
+        if version.contains([':', '-']) || s.contains(char::is_whitespace) {
+            return Err(ParseSemverError::UnrecognizedText)
+        }
+
+        Ok(Self { epoch, version: version.to_owned(), release: release.map(str::to_owned) })
+    }
+
+    /// The closest [`ReleaseKind`], for filtering
+    ///
+    /// Anything after a tilde is taken to be a pre-release, and a caret makes a patch.
+    pub fn rkind(&self) -> ReleaseKind {
+        match self.version.split_once('~') {
+            Some((_, pre)) => guess_kind(pre),
+            None if self.version.contains('^') => ReleaseKind::Patch,
+            None => ReleaseKind::Stable,
+        }
+    }
+}
+
+/// Compares two versions or releases like `rpmvercmp()`
+fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal
+    }
+
+    let is_sep = |c: &u8| !c.is_ascii_alphanumeric() && !matches!(c, b'~' | b'^');
+    let (mut one, mut two) = (a.as_bytes(), b.as_bytes());
+
+    while !one.is_empty() || !two.is_empty() {
+        while one.first().is_some_and(is_sep) { one = &one[1..]; }
+        while two.first().is_some_and(is_sep) { two = &two[1..]; }
+
+        // a tilde sorts before everything else
+        if one.first() == Some(&b'~') || two.first() == Some(&b'~') {
+            if one.first() != Some(&b'~') { return Ordering::Greater }
+            if two.first() != Some(&b'~') { return Ordering::Less }
+            one = &one[1..];
+            two = &two[1..];
+            continue
+        }
+
+        // a caret sorts like a tilde, except it sorts after the end of the string
+        if one.first() == Some(&b'^') || two.first() == Some(&b'^') {
+            if one.is_empty() { return Ordering::Less }
+            if two.is_empty() { return Ordering::Greater }
+            if one.first() != Some(&b'^') { return Ordering::Greater }
+            if two.first() != Some(&b'^') { return Ordering::Less }
+            one = &one[1..];
+            two = &two[1..];
+            continue
+        }
+
+        if one.is_empty() || two.is_empty() {
+            break
+        }
+
+        let isnum = one[0].is_ascii_digit();
+        let segment = |s: &[u8]| {
+            s.iter().take_while(|c| if isnum { c.is_ascii_digit() } else { c.is_ascii_alphabetic() }).count()
+        };
+        let (len1, len2) = (segment(one), segment(two));
+
+        // numeric segments are always newer than alpha segments
+        if len2 == 0 {
+            return if isnum { Ordering::Greater } else { Ordering::Less }
+        }
+
+        let (seg1, seg2) = (&one[..len1], &two[..len2]);
+        let ord = if isnum {
+            let seg1 = &seg1[seg1.iter().take_while(|&&c| c == b'0').count()..];
+            let seg2 = &seg2[seg2.iter().take_while(|&&c| c == b'0').count()..];
+            seg1.len().cmp(&seg2.len()).then_with(|| seg1.cmp(seg2))
+        } else {
+            seg1.cmp(seg2)
+        };
+
+        if ord.is_ne() {
+            return ord
+        }
+
+        one = &one[len1..];
+        two = &two[len2..];
+    }
+
+    // whichever version still has characters left over wins
+    match (one.is_empty(), two.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Less,
+        (false, _) => Ordering::Greater,
+    }
+}
+
+impl PartialEq for Rpm {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Rpm {}
+
+impl PartialOrd for Rpm {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rpm {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch.unwrap_or(0).cmp(&other.epoch.unwrap_or(0))
+            .then_with(|| rpmvercmp(&self.version, &other.version))
+            .then_with(|| match (&self.release, &other.release) {
+                (Some(a), Some(b)) => rpmvercmp(a, b),
+                (a, b) => a.is_some().cmp(&b.is_some()),
+            })
+    }
+}
+
+impl fmt::Display for Rpm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(epoch) = self.epoch {
+            write!(f, "{epoch}:")?;
+        }
+
+        write!(f, "{}", self.version)?;
+
+        if let Some(release) = &self.release {
+            write!(f, "-{release}")?;
+        }
+
+        Ok(())
+    }
+}
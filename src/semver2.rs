@@ -0,0 +1,127 @@
+use core::fmt;
+
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::{ParseSemverError, ReleaseKind, guess_kind};
+
+// from https://semver.org/#is-there-a-suggested-regular-expression-regex-to-check-a-semver-string
+static SEMVER2_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"^(0|[1-9]\d*)\.(0|[1-9]\d*)\.(0|[1-9]\d*)(?:-((?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+([0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$"#).expect("Invalid regex"));
+
+/// A dot-separated pre-release identifier, where numbers sort before text
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+pub enum Identifier {
+    Numeric(u64),
+    Alphanumeric(String),
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(n) => write!(f, "{n}"),
+            Self::Alphanumeric(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// A version following Semantic Versioning 2.0.0 to the letter
+///
+/// Build metadata is kept, but ignored for ordering and equality as per §10.
+#[derive(Debug, Default, Clone)]
+pub struct Semver2 {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: Vec<Identifier>,
+    pub build: Vec<String>,
+}
+
+impl Semver2 {
+    pub fn parse(s: &str) -> Result<Self, ParseSemverError> {
+        let s = s.trim();
+        let caps = SEMVER2_RE.captures(s).ok_or(if s.starts_with(|c: char| c.is_ascii_digit()) {
+            ParseSemverError::UnrecognizedText
+        } else {
+            ParseSemverError::MissingMajor
+        })?;
+
+        let num = |n: &str| n.parse::<u64>().map_err(|_| ParseSemverError::UnrecognizedText);
+
+        let pre = caps.get(4).map_or(Ok(Vec::new()), |m| {
+            m.as_str().split('.')
+                .map(|id| {
+                    if id.bytes().all(|b| b.is_ascii_digit()) {
+                        num(id).map(Identifier::Numeric)
+                    } else {
+                        Ok(Identifier::Alphanumeric(id.to_owned()))
+                    }
+                })
+                .collect()
+        })?;
+
+        let build = caps.get(5).map_or(Vec::new(), |m| m.as_str().split('.').map(str::to_owned).collect());
+
+        Ok(Self { major: num(&caps[1])?, minor: num(&caps[2])?, patch: num(&caps[3])?, pre, build })
+    }
+
+    /// The closest [`ReleaseKind`], for filtering, guessed from the first textual identifier
+    pub fn rkind(&self) -> ReleaseKind {
+        if self.pre.is_empty() {
+            return ReleaseKind::Stable
+        }
+
+        self.pre.iter()
+            .find_map(|id| match id {
+                Identifier::Alphanumeric(s) => Some(guess_kind(s)),
+                Identifier::Numeric(_) => None,
+            })
+            .unwrap_or(ReleaseKind::Pre)
+    }
+}
+
+impl PartialEq for Semver2 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Semver2 {}
+
+impl PartialOrd for Semver2 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Semver2 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major.cmp(&other.major)
+            .then_with(|| self.minor.cmp(&other.minor))
+            .then_with(|| self.patch.cmp(&other.patch))
+            // a release sorts after all of its pre-releases
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre.cmp(&other.pre),
+            })
+    }
+}
+
+impl fmt::Display for Semver2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        for (i, id) in self.pre.iter().enumerate() {
+            write!(f, "{}{id}", if i == 0 { '-' } else { '.' })?;
+        }
+
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build.join("."))?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use core::fmt;
+
+use crate::{Calver, Debian, Layout, ParseOptions, ParseSemverError, Pep440, Perl, Portage, ReleaseKind, Rpm, Semver, Semver2};
+
+/// A versioning scheme, deciding how versions are parsed and ordered
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Scheme {
+    /// Semantic(ish) versions, as understood by [`Semver`]
+    #[default]
+    Semver,
+    /// Python package versions, as understood by [`Pep440`]
+    Pep440,
+    /// Debian package versions, as understood by [`Debian`]
+    Debian,
+    /// RPM package versions, as understood by [`Rpm`]
+    Rpm,
+    /// Gentoo package versions, as understood by [`Portage`]
+    Portage,
+    /// Strict Semantic Versioning 2.0.0, as understood by [`Semver2`]
+    Semver2,
+    /// Perl module versions, as understood by [`Perl`]
+    Perl,
+    /// Calendar versions in some [`Layout`], as understood by [`Calver`]
+    Calver(Layout),
+}
+
+/// A version parsed under some [`Scheme`]
+///
+/// Versions from different schemes aren't meaningfully comparable, and simply sort by scheme.
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord)]
+pub enum Version {
+    Semver(Semver),
+    Pep440(Pep440),
+    Debian(Debian),
+    Rpm(Rpm),
+    Portage(Portage),
+    Semver2(Semver2),
+    Perl(Perl),
+    Calver(Calver),
+}
+
+impl Version {
+    pub fn parse_with(s: &str, opts: &ParseOptions) -> Result<Self, ParseSemverError> {
+        match opts.scheme {
+            Scheme::Semver => Semver::parse_with(s, opts).map(Self::Semver),
+            Scheme::Pep440 => Pep440::parse(s).map(Self::Pep440),
+            Scheme::Debian => Debian::parse(s).map(Self::Debian),
+            Scheme::Rpm => Rpm::parse(s).map(Self::Rpm),
+            Scheme::Portage => Portage::parse_with(s, opts).map(Self::Portage),
+            Scheme::Semver2 => Semver2::parse(s).map(Self::Semver2),
+            Scheme::Perl => Perl::parse(s).map(Self::Perl),
+            Scheme::Calver(layout) => Calver::parse_with(s, layout).map(Self::Calver),
+        }
+    }
+
+    /// The closest [`ReleaseKind`], for filtering
+    pub fn rkind(&self) -> ReleaseKind {
+        match self {
+            Self::Semver(semver) => semver.rkind,
+            Self::Pep440(pep440) => pep440.rkind(),
+            Self::Debian(debian) => debian.rkind(),
+            Self::Rpm(rpm) => rpm.rkind(),
+            Self::Portage(portage) => portage.rkind(),
+            Self::Semver2(semver2) => semver2.rkind(),
+            Self::Perl(perl) => perl.rkind(),
+            Self::Calver(calver) => calver.rkind(),
+        }
+    }
+
+    pub fn as_semver(&self) -> Option<&Semver> {
+        match self {
+            Self::Semver(semver) => Some(semver),
+            _ => None,
+        }
+    }
+
+    pub fn display_with<'a>(&'a self, opts: &'a ParseOptions) -> impl fmt::Display + 'a {
+        fmt::from_fn(move |f| match self {
+            Self::Semver(semver) => write!(f, "{}", semver.display_with(opts)),
+            Self::Pep440(pep440) => write!(f, "{pep440}"),
+            Self::Debian(debian) => write!(f, "{debian}"),
+            Self::Rpm(rpm) => write!(f, "{rpm}"),
+            Self::Portage(portage) => write!(f, "{portage}"),
+            Self::Semver2(semver2) => write!(f, "{semver2}"),
+            Self::Perl(perl) => write!(f, "{}", perl.display_as(opts.perl_form.unwrap_or(perl.form))),
+            Self::Calver(calver) => write!(f, "{}", calver.display_as(opts.calver_form.unwrap_or(calver.layout))),
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.display_with(&ParseOptions::new()))
+    }
+}
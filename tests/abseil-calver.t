@@ -0,0 +1 @@
+abseil-cpp-tags.t
\ No newline at end of file
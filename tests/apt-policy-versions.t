@@ -0,0 +1,567 @@
+408.0.1-0
+404.0.0-0
+524.0.0-0
+458.0.1-0
+5.3.28+dfsg2-1
+421.0.0-0
+473.0.0-0
+501.0.0-0
+424.0.0-0
+0.40.0-4
+1:470.0.0-0
+0.5.1-6
+5.2.15-2+b8
+405.0.1-0
+390.0.0-0
+1:527.0.0-0
+1:538.0.0-0
+15.17-0+deb12u1
+2:1.02.185-2
+3.8.1-2
+2.38.1-5+deb12u3
+1:474.0.0-0
+509.0.0-0
+1:558.0.0-0
+1:2.66-4+deb12u3+b1
+511.0.0-0
+1:3.8-4
+1:471.0.0-0
+1:534.0.0-0
+2.3.3-9
+521.0.0-0
+1:467.0.0-0
+3.4-1+b5
+532.0.0-0
+1.201-1
+568.0.0-0
+3.11.2-6+deb12u7
+539.0.0-0
+1:481.0.0-0
+1:483.0.0-0
+435.0.1-0
+2.38.1-5+deb12u1
+0.16-2
+1.6-2.1+deb12u1
+2023.3+deb12u2
+446.0.0-0
+549.0.0-0
+1.20.7-10+b1
+494.0.0-0
+0.58+deb12u6
+43.0-1
+3.4-1+b6
+457.0.0-0
+453.0.0-0
+528.0.0-0
+462.0.1-0
+1.6.0-1
+1:529.0.0-0
+1.12-1
+1:524.0.0-0
+5.2.15-2+b13
+394.0.0-0
+476.0.0-0
+380.0.0-0
+1:522.0.0-0
+1.5.2-6+deb12u2
+1:523.0.1-0
+1.50.12+ds-1
+1:3.6.0-7.1
+1:465.0.0-0
+371.0.0-0
+1.4.1+dfsg-1
+1:489.0.0-0
+1:563.0.0-0
+1:1.1.4-1
+482.0.0-0
+3.24.38-2~deb12u3
+426.0.0-0
+1:555.0.0-0
+1.22.0-2+deb12u1
+1:536.0.0-0
+1.9.4-1
+1:537.0.0-0
+10.42-1
+1:497.0.0-0
+1:536.0.1-0
+1.0.8-5+b1
+515.0.0-0
+505.0.0-0
+20230311+deb12u1
+0.2.13-2+b1
+498.0.0-0
+434.0.0-0
+465.0.0-0
+1.10.0-3+b1
+403.0.0-0
+1:550.0.0-0
+1:2.38.1-5+deb12u1
+1:552.0.0-0
+1:2.39.5-0+deb12u2
+2.46.0-5
+391.0.0-0
+512.0.0-0
+478.0.0-0
+401.0.0-0
+1:460.0.0-0
+454.0.0-0
+504.0.0-0
+513.0.0-0
+503.0.0-0
+2:2.6.1-4~deb12u2
+417.0.0-0
+1:566.0.0-0
+1:462.0.1-0
+1:568.0.0-0
+1:464.0.0-0
+464.0.0-0
+561.0.0-0
+458.0.0-0
+2.1.28+dfsg-10
+536.0.1-0
+470.0.0-0
+485.0.0-0
+519.0.0-0
+1:473.0.0-0
+1:508.0.0-0
+543.0.0-0
+2.4.114-1
+461.0.0-0
+436.0.0-0
+3.1-20221030-2
+3.0.20-1~deb12u1
+381.0.0-0
+435.0.0-0
+1:2.5.1-4
+2:4.0.2-3
+569.0.0-0
+1.10.1-3
+3.134
+1:505.0.0-0
+1:488.0.0-0
+1:515.0.0-0
+475.0.0-0
+1:5.44-3
+15.18-0+deb12u1
+497.0.0-0
+1.2.5.1-2
+2.5.5-5
+1:462.0.0-0
+2.37-6
+1.31-1.2
+1:482.0.0-0
+0.11.1-1+deb12u1
+1:511.0.0-0
+416.0.0-0
+1:461.0.0-0
+442.0.0-0
+417.0.1-0
+5.36.0-7+deb12u3
+1:565.0.0-0
+2.74.6-2+deb12u9
+553.0.0-0
+446.0.1-0
+516.0.0-0
+2.24.33-2+deb12u1
+2:1.2.3-1
+558.0.0-0
+1:514.0.0-0
+2:4.35-1
+1:516.0.0-0
+3.0.17-1~deb12u2
+1:518.0.0-0
+397.0.0-0
+72.1-3+deb12u1
+1:495.0.0-0
+2.4+20151223.gitfa8646d.1-2+b2
+5.7-0.5~deb12u1
+0.1.29-1
+1:507.0.0-0
+449.0.0-0
+1:521.0.0-0
+1:491.0.0-0
+1.5.2-6+deb12u1
+479.0.0-0
+1.74.0-3
+430.0.0-0
+523.0.0-0
+0.17029-2
+1:14.0.6-12
+510.0.0-0
+388.0.0-0
+0.21.2-1
+480.0.0-0
+489.0.0-0
+3.7.9-2+deb12u7
+2.7.0-2
+531.0.0-0
+1:498.0.0-0
+2.1-6.1
+1.15.1-1+deb12u1
+496.0.0-0
+373.0.0-0
+1:546.0.0-0
+375.0.0-0
+1:548.0.0-0
+377.0.0-0
+1:549.0.1-0
+379.0.0-0
+1:493.0.0-0
+1:554.0.0-0
+555.0.0-0
+1.12.0-2+b1
+0.5.12-2
+1:513.0.0-0
+384.0.1-0
+1.16.0-7
+386.0.0-0
+1:560.0.0-0
+1:526.0.0-0
+1:562.0.0-0
+0.24.1-2
+1:564.0.0-0
+522.0.0-0
+455.0.0-0
+374.0.0-0
+1:569.0.0-0
+520.0.0-0
+490.0.0-0
+2.74.6-2+deb12u2
+1.21.23
+402.0.0-0
+423.0.0-0
+468.0.0-0
+1:542.0.0-0
+395.0.0-0
+551.0.0-0
+0~20171227-0.3+deb12u1
+400.0.0-0
+2:3.87.1-1+deb12u2
+1.26.1-00
+2.42.10+dfsg-1+deb12u2
+1.0.4-3
+0.17-2
+471.0.0-0
+3.11.2-6+deb12u6
+1.20.1-2+deb12u2
+408.0.0-0
+1:466.0.0-0
+409.0.0-0
+2.4.2-3+deb12u8
+411.0.0-0
+2.40-2
+413.0.0-0
+2.42.10+dfsg-1+deb12u4
+415.0.0-0
+2.5.0-1+deb12u1
+1:463.0.0-0
+488.0.0-0
+492.0.0-0
+1:2.5.1-4+b2
+1.0.4-2
+507.0.0-0
+1.0.0-2+deb12u1
+1.8.9-2
+122-3
+389.0.0-0
+548.0.0-0
+427.0.0-0
+1.8.1-1
+429.0.0-0
+22.3.6-1+deb12u1
+1:485.0.0-0
+252.39-1~deb12u2
+431.0.0-0
+0.40.0-3
+433.0.0-0
+1:2.39.5-0+deb12u3
+1.9.9-2
+1:479.0.0-0
+1.07-5
+2:6.2.1+dfsg1-1.1
+437.0.0-0
+3.0.19-1~deb12u2
+3.6.1+dfsg+~3.5.14-1
+1:530.0.0-0
+556.0.0-0
+1:459.0.0-0
+2:1.0.10-1
+420.0.0-0
+5.2.1-2.5
+1:2.38.1-5+deb12u3
+1:503.0.0-0
+541.0.0-0
+550.0.0-0
+1:517.0.0-0
+495.0.0-0
+428.0.0-0
+392.0.0-0
+1.0.18-1+deb12u1
+491.0.0-0
+2.0.16-1
+2.12.1+dfsg-5+deb12u4
+1.65.2
+2.3.1-3
+530.0.0-0
+463.0.0-0
+3.6.1
+504.0.1-0
+405.0.0-0
+1.14-1
+444.0.0-0
+372.0.0-0
+484.0.0-0
+406.0.0-0
+517.0.0-0
+443.0.0-0
+557.0.0-0
+483.0.0-0
+1:540.0.0-0
+523.0.1-0
+2.6.1
+7.88.1-10+deb12u5
+481.0.0-0
+1:512.0.0-0
+1.47.0-2
+487.0.0-0
+410.0.0-0
+3.6.0-1+deb12u2
+2.14-2
+469.0.0-0
+425.0.0-0
+2.36-9+deb12u7
+4.13.0-1
+11+nmu1
+1:561.0.0-0
+376.0.0-0
+537.0.0-0
+1:468.0.0-0
+559.0.0-0
+526.0.1-0
+533.0.0-0
+422.0.0-0
+3.4-2.1
+0.8.3-1+b3
+4.15.0-1
+1:535.0.0-0
+4.2.0-1
+1.6-3
+1:477.0.0-0
+4:12.2.0-3
+1.47.0-2+b2
+12.9
+0.08-5
+5.3.0-4
+0.22-4+b1
+5.36.0-7+deb12u2
+407.0.0-0
+5.4.1-1
+0.04-8+b1
+500.0.0-0
+1:539.0.0-0
+502.0.0-0
+412.0.0-0
+12.4+deb12u14
+414.0.0-0
+3.0.8-3
+2.3.3-1+b1
+2.36-9+deb12u14
+1:496.0.0-0
+1.6.39-2+deb12u5
+419.0.0-0
+0.74
+1:476.0.0-0
+2.54.7+dfsg-1~deb12u1
+1:543.0.0-0
+1.65.2+deb12u1
+0.8.0-2+b1
+3.7.9-2+deb12u6
+2.5.13+dfsg-5
+1:2.66-4+deb12u2+b2
+1:486.0.0-0
+1:549.0.0-0
+1.46-1
+1:523.0.0-0
+384.0.0-0
+1:504.0.1-0
+1:499.0.0-0
+526.0.0-0
+1:545.0.0-0
+527.0.0-0
+20230710~deb12u1
+529.0.0-0
+252.38-1~deb12u1
+1:531.0.0-0
+438.0.0-0
+0.42.2-1
+440.0.0-0
+535.0.0-0
+1:492.0.0-0
+1:15.0.6-4+b1
+3.6.0-1+deb12u1
+538.0.0-0
+1.13.4~dfsg+~1.11.4-3
+540.0.0-0
+447.0.0-0
+542.0.0-0
+1:506.0.0-0
+544.0.0-0
+451.0.0-0
+546.0.0-0
+452.0.0-0
+2.5.0-1+deb12u2
+1.2.8-1+b1
+549.0.1-0
+1:556.0.0-0
+0.58+deb12u7
+1.2.8-1
+1:490.0.0-0
+1:3.0.9-1
+1:544.0.0-0
+20220623.1-1+deb12u2
+3.8-5
+1.20.1-2+deb12u3
+387.0.0-0
+1.3.14-1
+0.8-10+deb12u1
+466.0.0-0
+563.0.0-0
+1:532.0.0-0
+565.0.0-0
+1:494.0.0-0
+567.0.0-0
+472.0.0-0
+1:475.0.0-0
+474.0.0-0
+6.0.0+dfsg-3
+1.23-3
+6.4-4
+1:519.0.0-0
+7.88.1-10+deb12u14
+0.25-1.1
+1:510.0.0-0
+1.28.2-00
+8.6.13+dfsg-2
+1.0.9-2+b6
+9.1-1
+1:567.0.0-0
+0.270
+399.0.0-0
+1:547.0.0-0
+1:557.0.0-0
+1:469.0.0-0
+1.27.4-00
+1:520.0.0-0
+3.11.2-1+b1
+3.11.2-6+deb12u3
+418.0.0-0
+451.0.1-0
+564.0.0-0
+1.21.22
+1:500.0.0-0
+2.74.6-2+deb12u8
+4.9.0-4
+385.0.0-0
+0.18.0-1+b1
+1:541.0.0-0
+2.2.40-1.1+deb12u2
+378.0.0-0
+3.4-1
+398.0.0-0
+1:528.0.0-0
+383.0.1-0
+1:4.4.33-2
+452.0.1-0
+445.0.0-0
+1:525.0.0-0
+1.3.0-2
+8.2-1.3
+1.52.0-1+deb12u3
+493.0.0-0
+1:559.0.0-0
+1:480.0.0-0
+0:459.0.0-0
+1:472.0.0-0
+499.0.0-0
+1.2.6-5+deb12u1
+1:478.0.0-0
+1:501.0.0-0
+1.14.10-1~deb12u1
+1:487.0.0-0
+383.0.0-0
+2.5.4-1+deb12u1
+0.11.7-2
+477.0.0-0
+2.4.2-3+deb12u9
+0.4-1
+1.3.1-1
+525.0.0-0
+460.0.0-0
+1:484.0.0-0
+433.0.1-0
+4.10000-1
+43-1
+2.1.12-stable-8
+486.0.0-0
+1:551.0.0-0
+1.5.82
+3.40.1-2+deb12u2
+396.0.0-0
+441.0.0-0
+514.0.0-0
+393.0.0-0
+554.0.0-0
+30+20221128-1
+545.0.0-0
+534.0.0-0
+560.0.0-0
+12.2.0-14+deb12u1
+370.0.0-0
+1:2.1.5-2
+1.0.8-2.1
+1:533.0.0-0
+3.23+nmu1
+518.0.0-0
+0.188-2.1
+2.14-2+deb12u1
+1.6.3-2
+1.52.0-1+deb12u2
+3.4.4-1
+6.1.0-3
+1:504.0.0-0
+536.0.0-0
+1:1.1.2-0+deb12u1
+590-2.1~deb12u2
+439.0.0-0
+432.0.0-0
+1.20.1-2+deb12u4
+450.0.0-0
+1:553.0.0-0
+456.0.0-0
+4.19.0-2+deb12u1
+566.0.0-0
+437.0.1-0
+462.0.0-0
+12.4+deb12u11
+1.0.8-5
+1:502.0.0-0
+552.0.0-0
+4.0.0+ds-2
+1.6.39-2+deb12u4
+2.4.114-1+b1
+467.0.0-0
+2.2.0-2
+1.0.11-1+deb12u2
+382.0.0-0
+0.16.1-2
+508.0.0-0
+562.0.0-0
+3.4.0-1
+448.0.0-0
+1:526.0.1-0
+6.9.8-1
+506.0.0-0
+1:509.0.0-0
+9.0.2-1.1
+547.0.0-0
+2.14.1-4
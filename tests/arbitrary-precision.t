@@ -0,0 +1,6 @@
+20250101123045123456789
+20250101123045123456788
+20241231235959999999999
+20250101123045123456789.1
+9.0
+20250101123045123456789-rc1
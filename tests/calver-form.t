@@ -0,0 +1 @@
+calver.t
\ No newline at end of file
@@ -0,0 +1,4 @@
+4.2025.1
+5.2024.1
+4.2024.12
+5.2025.3
@@ -0,0 +1,8 @@
+24.04
+22.04.3
+24.04.1
+23.10
+20.04
+22.04
+24.10
+22.04.1
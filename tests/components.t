@@ -0,0 +1,10 @@
+3.31.0.2.9
+120.0.6099.109
+3.31.0.2.1
+3.31.0
+10.0.19045.3803
+3.31
+10.0.19045.3570
+120.0.6099.71
+3.31.0.2
+10.0.22621.2861
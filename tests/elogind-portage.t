@@ -0,0 +1 @@
+elogind-tags.t
\ No newline at end of file
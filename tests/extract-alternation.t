@@ -0,0 +1,4 @@
+foo-2.0.tar.gz
+bar_v1.10
+foo-1.9.tar.gz
+bar_v1.2
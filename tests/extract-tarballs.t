@@ -0,0 +1,5 @@
+foo-2.0.1.tar.xz
+foo-1.10.tar.gz
+foo-bar-1.9.tar.xz
+foo-2.0.1-rc2.tar.xz
+foo-1.2.tar.bz2
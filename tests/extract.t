@@ -0,0 +1,6 @@
+a1b2c3	refs/tags/release-1.10.0
+d4e5f6	refs/tags/release-1.9.2
+0a1b2c	refs/tags/release-2.0.0-rc1
+9f8e7d	refs/tags/nightly
+3c4d5e	refs/tags/release-1.2.3
+7a8b9c	refs/tags/release-2.0.0
@@ -0,0 +1,2 @@
+1.3.0
+0.9.0
@@ -0,0 +1 @@
+gsettings-desktop-schemas-tags.t
\ No newline at end of file
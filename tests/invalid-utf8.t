@@ -0,0 +1,9 @@
+2.0
+1.10-
+
+1.9
+1.2.3
+1.3-caf.tar
+12
+1.5
+13
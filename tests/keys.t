@@ -0,0 +1,8 @@
+zlib	1.3.1	2024-01-22
+openssl	3.0.13	2024-01-30
+curl	8.5.0	2023-12-06
+bzip2	1.0.8	2019-07-13
+xz	5.4.6	2024-01-26
+libfoo	1.3.1	2024-02-01
+bar	3.0.13-rc1	2024-01-01
+abc	8.5.0	2023-12-07
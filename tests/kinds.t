@@ -0,0 +1,9 @@
+1.2.0-dev
+1.1.0
+1.2.0-alpha3
+1.2.0-rc1
+1.2.0-pre
+1.1.0p2
+1.2.0-beta1
+1.2.0
+1.2.0-next
@@ -0,0 +1,4 @@
+2.0.0-rc1
+1.9.0
+2.0.0
+1.9.1-beta1
@@ -0,0 +1,6 @@
+1.0.0
+18446744073709551615.2.0
+0.1.0
+18446744073709551614.9.9
+0.0.18446744073709551615
+18446744073709551615.0.0
@@ -0,0 +1,20 @@
+0.9.0
+1.0.0-rc.1
+1.0.0
+1.2.0
+1.2.3-rc1
+1.2.3
+1.2.4-beta1
+1.2.4
+1.3.0
+1.3.0-rc1
+2.0.0
+2.3.4
+2.3.5
+2.4.0-alpha1
+0.2.3
+0.2.9
+0.3.0
+0.0.3
+0.0.4
+3.1.0
@@ -0,0 +1 @@
+padding.t
\ No newline at end of file
@@ -0,0 +1,10 @@
+1
+001
+1.9
+1.10
+01
+2
+002
+1.05
+1.5
+1.0.1
@@ -0,0 +1,17 @@
+1.0+ubuntu1
+1.0.post1
+1!0.5
+1.0a1
+1.0.dev1
+1.0b2.post3.dev4
+1.0
+1.0rc1
+1.0a1.dev2
+1.0+1
+0.9
+1.0-1
+1.0.post1.dev1
+1.0a1.post1
+1.0c2
+v1.0-dev3
+2.0.0
@@ -0,0 +1 @@
+perl.t
\ No newline at end of file
@@ -0,0 +1,14 @@
+5.036000
+v5.36.0
+1.10
+1.9
+v1.9
+1.23_01
+1.23
+v1.2.3
+1.002003
+0.01
+5
+v5.8.1
+5.008001
+5.8.1
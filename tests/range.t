@@ -0,0 +1,10 @@
+2.6.9
+3.0
+2.7
+2.10.1
+2.7.0-rc1
+2.8
+2.9.4
+3.0.0-beta1
+2.6
+2.8.1
@@ -0,0 +1,9 @@
+1.9
+2.0
+001
+2.0-rc1
+1
+2.0.0
+2.0rc1
+2.0
+1.10
@@ -0,0 +1,15 @@
+6.11.0-0.rc7.20240913git5f5673607153.56.fc42
+1:2.39.2-1.fc38
+6.10.10-200.fc40
+1.0^20240101git1a2b3c-1.fc40
+6.11.0-63.fc41
+1.0~rc2-1.fc40
+1.0-1.fc40
+2.39.2-1.fc38
+1.0~rc1-1.fc40
+6.10.9-200.fc40
+1.0.1-1.fc40
+6.11.0-0.rc6.49.fc42
+1.0^20231201git9f8e7d-1.fc40
+1.0-2.fc40
+1.0~beta3-0.1.fc39
@@ -0,0 +1,10 @@
+3.1
+v-latest
+2.0-rc.1
+3.0.0_beta2
+1.9
+3.0.0
+3.1.0
+nightly
+3.0
+2.10
@@ -0,0 +1,13 @@
+1.0.0-rc.1
+1.0.0-beta.11
+1.0.0+build.5
+1.0.0-alpha.beta
+1.0.0-x.7.z.92
+2.0.0+20130313144700
+1.0.0-beta
+1.0.0-alpha.1
+1.0.0
+1.0.0-beta.2
+1.0.0-alpha+001
+1.0.0-alpha
+1.0.0-0.3.7
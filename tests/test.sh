@@ -23,7 +23,8 @@ teststatus() {
 }
 
 runtest() {
-    < "$1" "$VERSORT" $(<"$1.flags") | diff - --color=always -u "$1.out" >&2
+    # flags may name other files in the tests directory
+    (cd "$SCRIPT_DIR" && < "$1" "$VERSORT" $(<"$1.flags")) | diff - --color=always -u "$1.out" >&2
 }
 
 for t in "$SCRIPT_DIR"/*.t; do
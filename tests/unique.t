@@ -0,0 +1,11 @@
+2.0
+001
+1.0
+2.0.0
+1
+1.0-rc.1
+2.0
+1.0rc1
+002
+2
+1.00